@@ -0,0 +1,210 @@
+use crate::error::Error;
+use crate::runner::timing::{repeat, Timings};
+use crate::solutions::*;
+use std::fmt::{self, Display, Formatter};
+use std::panic::resume_unwind;
+use std::thread;
+use std::time::Duration;
+
+/// Solver for the puzzle of a single day of the calendar.
+pub trait Day {
+    /// Parsed puzzle input, shared by both parts, which may be solved concurrently.
+    type Input: Sync;
+
+    const DAY: u32;
+    const TITLE: &'static str;
+    /// Parts of the puzzle. The last day of the calendar only has a first part.
+    const PARTS: Parts = Parts::Both;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> impl Display;
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+/// Implements [`Day`] by forwarding to the `parse`, `part1` and `part2` functions of the calling
+/// module, which is how most solutions are written.
+macro_rules! impl_day {
+    ($day:ident, $input:ty, $number:literal, $title:literal) => {
+        impl $crate::day::Day for $day {
+            type Input = $input;
+
+            const DAY: u32 = $number;
+            const TITLE: &'static str = $title;
+
+            fn parse(input: &str) -> Result<Self::Input, $crate::error::Error> {
+                parse(input)
+            }
+
+            fn part1(input: &Self::Input) -> impl std::fmt::Display {
+                part1(input)
+            }
+
+            fn part2(input: &Self::Input) -> impl std::fmt::Display {
+                part2(input)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_day;
+
+/// Answer to a part which some inputs have none for, such as the length of a blocked path. A
+/// missing answer is displayed as the given reason, rather than as a made-up value.
+pub struct Answer<T> {
+    answer: Option<T>,
+    missing: &'static str,
+}
+
+impl<T> Answer<T> {
+    pub fn new(answer: Option<T>, missing: &'static str) -> Self {
+        Self { answer, missing }
+    }
+}
+
+impl<T: Display> Display for Answer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.answer {
+            Some(answer) => answer.fmt(f),
+            None => f.write_str(self.missing),
+        }
+    }
+}
+
+/// Type-erased [`Day`], which allows driving all solvers uniformly.
+pub struct Solution {
+    pub day: u32,
+    pub title: &'static str,
+    /// Solves the puzzle from the given input.
+    pub wrapper: fn(&str, Config) -> Result<Run, Error>,
+}
+
+impl Solution {
+    pub fn new<D: Day>() -> Self {
+        Self {
+            day: D::DAY,
+            title: D::TITLE,
+            wrapper: |data, config| {
+                let Config {
+                    runs,
+                    parts,
+                    concurrent,
+                } = config;
+
+                let (input, parse) = repeat(runs, || D::parse(data));
+                let input = input.map_err(|e| e.with_day(D::DAY))?;
+
+                let part1 = || solve(parts.part1() && D::PARTS.part1(), runs, || D::part1(&input));
+                let part2 = || solve(parts.part2() && D::PARTS.part2(), runs, || D::part2(&input));
+                let ((part1, part1_durations), (part2, part2_durations)) = if concurrent {
+                    thread::scope(|s| {
+                        let part2 = s.spawn(part2);
+                        (part1(), part2.join().unwrap_or_else(|e| resume_unwind(e)))
+                    })
+                } else {
+                    (part1(), part2())
+                };
+
+                Ok(Run {
+                    part1,
+                    part2,
+                    timings: Timings {
+                        parse,
+                        part1: part1_durations,
+                        part2: part2_durations,
+                    },
+                })
+            },
+        }
+    }
+}
+
+// Solves a part unless it is excluded, and returns its answer along with the duration of each run.
+fn solve<T: Display>(
+    included: bool,
+    runs: usize,
+    f: impl FnMut() -> T,
+) -> (Option<String>, Vec<Duration>) {
+    if !included {
+        return (None, Vec::new());
+    }
+    let (answer, durations) = repeat(runs, f);
+    (Some(answer.to_string()), durations)
+}
+
+/// Settings for running a [`Solution`].
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// Number of times each phase is repeated.
+    pub runs: usize,
+    pub parts: Parts,
+    /// Whether both parts are solved concurrently.
+    pub concurrent: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            runs: 1,
+            parts: Parts::Both,
+            concurrent: false,
+        }
+    }
+}
+
+/// Parts of a puzzle to solve.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Parts {
+    #[default]
+    Both,
+    Part1,
+    Part2,
+}
+
+impl Parts {
+    pub fn part1(self) -> bool {
+        self != Parts::Part2
+    }
+
+    pub fn part2(self) -> bool {
+        self != Parts::Part1
+    }
+}
+
+/// Answers produced by a [`Solution`], along with the time spent in each phase. Parts which were
+/// not solved have no answer.
+pub struct Run {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub timings: Timings,
+}
+
+/// All solutions, ordered by day.
+pub fn registry() -> Vec<Solution> {
+    vec![
+        Solution::new::<day01::Day01>(),
+        Solution::new::<day02::Day02>(),
+        Solution::new::<day03::Day03>(),
+        Solution::new::<day04::Day04>(),
+        Solution::new::<day05::Day05>(),
+        Solution::new::<day06::Day06>(),
+        Solution::new::<day07::Day07>(),
+        Solution::new::<day08::Day08>(),
+        Solution::new::<day09::Day09>(),
+        Solution::new::<day10::Day10>(),
+        Solution::new::<day11::Day11>(),
+        Solution::new::<day12::Day12>(),
+        Solution::new::<day13::Day13>(),
+        Solution::new::<day14::Day14>(),
+        Solution::new::<day15::Day15>(),
+        Solution::new::<day16::Day16>(),
+        Solution::new::<day17::Day17>(),
+        Solution::new::<day18::Day18>(),
+        Solution::new::<day19::Day19>(),
+        Solution::new::<day20::Day20>(),
+        Solution::new::<day21::Day21>(),
+        Solution::new::<day22::Day22>(),
+        Solution::new::<day23::Day23>(),
+        Solution::new::<day24::Day24>(),
+        Solution::new::<day25::Day25>(),
+    ]
+}
@@ -0,0 +1,55 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Error encountered while parsing a puzzle input.
+///
+/// Lines and columns are 1-based. The day is unknown to the parsers themselves, and gets attached
+/// by the [`Solution`](crate::Solution) which invoked them.
+#[derive(Debug, PartialEq)]
+pub struct Error {
+    pub day: Option<u32>,
+    pub line: usize,
+    pub column: usize,
+    pub reason: String,
+}
+
+impl Error {
+    pub fn new(line: usize, column: usize, reason: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            reason: reason.into(),
+        }
+    }
+
+    /// Moves the error down by the given number of lines, for an error found in a part of the input
+    /// which starts after them.
+    pub fn below(self, lines: usize) -> Self {
+        Self {
+            line: self.line + lines,
+            ..self
+        }
+    }
+
+    pub fn with_day(self, day: u32) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day:02}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )
+    }
+}
+
+impl std::error::Error for Error {}
@@ -1,3 +1,9 @@
+pub mod day;
+pub mod error;
+
+pub use day::{registry, Answer, Config, Day, Parts, Run, Solution};
+pub use error::Error;
+
 pub mod solutions {
     pub mod day01;
     pub mod day02;
@@ -10,9 +16,31 @@ pub mod solutions {
     pub mod day09;
     pub mod day10;
     pub mod day11;
+    pub mod day12;
+    pub mod day13;
+    pub mod day14;
+    pub mod day15;
+    pub mod day16;
+    pub mod day17;
+    pub mod day18;
+    pub mod day19;
+    pub mod day20;
+    pub mod day21;
+    pub mod day22;
+    pub mod day23;
+    pub mod day24;
+    pub mod day25;
+}
+
+pub mod runner {
+    pub mod answers;
+    pub mod report;
+    pub mod timing;
 }
 
 pub mod util {
+    pub mod grid;
     pub mod integer;
     pub mod parse;
+    pub mod point;
 }
@@ -1,74 +1,585 @@
-use aoc2024::solutions;
-use aoc2024::util::parse::ParseOps;
-use std::env::args;
-use std::fs::read_to_string;
+use aoc2024::registry;
+use aoc2024::runner::answers::{Answers, Verdict};
+use aoc2024::runner::report::{self, Record};
+use aoc2024::runner::timing::Stats;
+use aoc2024::{Config, Error, Parts, Run, Solution};
+use std::env::{args, var_os};
+use std::fmt::{self, Display, Formatter};
+use std::fs::{read_dir, read_to_string};
+use std::io::{self, stdin, ErrorKind};
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+use std::sync::Mutex;
+use std::thread::{self, available_parallelism};
+use std::time::{Duration, Instant};
 
 fn main() {
-    let day = match args().nth(1) {
-        Some(day) => day.as_str().iter_unsigned().next(),
-        None => None,
+    let options = match Options::parse() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}");
+            exit(2);
+        }
+    };
+
+    let solutions = registry();
+    let jobs = match jobs(&solutions, &options) {
+        Ok(jobs) => jobs,
+        Err(e) => {
+            eprintln!("{e}");
+            exit(2);
+        }
     };
 
-    let solutions = solutions()
-        .into_iter()
-        .filter(|solution| day.is_none_or(|day: u32| day == solution.day));
+    let start = Instant::now();
+    let outcomes: Vec<_> = match options.threads {
+        Some(threads) => run_parallel(jobs, threads, options.config),
+        None => jobs
+            .into_iter()
+            .map(|job| job.run(options.config))
+            .collect(),
+    };
+    let wall_clock = start.elapsed();
+
+    let failures: usize = outcomes.iter().map(Outcome::failures).sum();
+
+    match options.format {
+        Format::Json => print!("{}", report::json(&records(&outcomes))),
+        Format::Csv => print!("{}", report::csv(&records(&outcomes))),
+        Format::Text if options.batch.is_some() => print_table(&outcomes),
+        Format::Text => print_outcomes(&outcomes, options.bench),
+    }
+
+    if let (Format::Text, Some(threads)) = (options.format, options.threads) {
+        let phases: Duration = outcomes
+            .iter()
+            .filter_map(|outcome| outcome.run.as_ref().ok())
+            .flat_map(|run| {
+                let timings = &run.timings;
+                [&timings.parse, &timings.part1, &timings.part2]
+            })
+            .flatten()
+            .sum();
+        println!("Wall-clock: {wall_clock:.1?}, sum of phases: {phases:.1?}, threads: {threads}");
+    }
 
-    for Solution { day, path, wrapper } in solutions {
-        if let Ok(data) = read_to_string(&path) {
-            let (part1, part2) = wrapper(data);
+    if failures > 0 {
+        exit(1);
+    }
+}
+
+// Pairs the selected solutions with the input(s) they should run against.
+fn jobs<'a>(solutions: &'a [Solution], options: &Options) -> Result<Vec<Job<'a>>, String> {
+    if let Some(day) = options
+        .days
+        .iter()
+        .find(|day| !solutions.iter().any(|solution| solution.day == **day))
+    {
+        return Err(format!("No solution for day {day}"));
+    }
+
+    let mut solutions = solutions
+        .iter()
+        .filter(|solution| options.days.is_empty() || options.days.contains(&solution.day));
+
+    if let Some(dir) = &options.batch {
+        let solution = solutions.next().ok_or("--batch requires a solved day")?;
+
+        let mut inputs: Vec<_> = read_dir(dir)
+            .map_err(|e| format!("Unable to read {}: {e}", dir.display()))?
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "txt"))
+            .collect();
+        inputs.sort();
+
+        return Ok(inputs
+            .into_iter()
+            .map(|input| {
+                let answers = Some(input.with_extension("answer")).filter(|a| a.exists());
+                Job {
+                    solution,
+                    input,
+                    answers,
+                }
+            })
+            .collect());
+    }
+
+    // Answers are stored in a directory next to the inputs, or next to an explicit input like in
+    // batch mode.
+    let input_dir = var_os("AOC_INPUT_DIR").map_or_else(|| PathBuf::from("input"), PathBuf::from);
+    let answers_dir = input_dir.with_file_name("answers");
+
+    Ok(solutions
+        .map(|solution| {
+            let day = solution.day;
+            let (input, answers) = match &options.input {
+                Some(path) => (
+                    PathBuf::from(path),
+                    Path::new(path).with_extension("answer"),
+                ),
+                None => (
+                    input_dir.join(format!("day{day:02}")).with_extension("txt"),
+                    answers_dir
+                        .join(format!("day{day:02}"))
+                        .with_extension("txt"),
+                ),
+            };
+            let answers = options.verify.then_some(answers);
+            Job {
+                solution,
+                input,
+                answers,
+            }
+        })
+        .collect())
+}
+
+// Runs jobs on a pool of threads, and returns their outcomes in the same order as the jobs.
+//
+// Threads of the pool which are not running a job solve the second part of a job concurrently
+// with its first part, so no more than the given number of threads run at once.
+fn run_parallel<'a>(jobs: Vec<Job<'a>>, threads: usize, config: Config) -> Vec<Outcome<'a>> {
+    let workers = threads.min(jobs.len());
+    let spare = AtomicUsize::new(threads - workers);
+    let jobs = Mutex::new(jobs.into_iter().enumerate());
+    let outcomes = Mutex::new(Vec::new());
+
+    thread::scope(|s| {
+        for _ in 0..workers {
+            s.spawn(|| {
+                loop {
+                    let Some((i, job)) = jobs.lock().unwrap().next() else {
+                        break;
+                    };
+                    let concurrent = spare
+                        .fetch_update(SeqCst, SeqCst, |n| n.checked_sub(1))
+                        .is_ok();
+                    let outcome = job.run(Config {
+                        concurrent,
+                        ..config
+                    });
+                    if concurrent {
+                        spare.fetch_add(1, SeqCst);
+                    }
+                    outcomes.lock().unwrap().push((i, outcome));
+                }
+                spare.fetch_add(1, SeqCst);
+            });
+        }
+    });
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|(i, _)| *i);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
 
-            println!("Day {day:02}");
-            println!("    Part 1: {part1}");
-            println!("    Part 2: {part2}");
+struct Job<'a> {
+    solution: &'a Solution,
+    // Path to the input, or "-" for stdin.
+    input: PathBuf,
+    // Path to the expected answers, if they should be verified.
+    answers: Option<PathBuf>,
+}
+
+impl<'a> Job<'a> {
+    fn run(self, config: Config) -> Outcome<'a> {
+        let data = if self.input == Path::new("-") {
+            io::read_to_string(stdin())
         } else {
-            eprintln!("Day {day:02}");
-            eprintln!("    Missing input in {}", path.display());
+            read_to_string(&self.input)
+        };
+
+        let run = match data {
+            Ok(data) => (self.solution.wrapper)(&data, config).map_err(Failure::InvalidInput),
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(Failure::MissingInput),
+            Err(e) => Err(Failure::Unreadable(e)),
+        };
+
+        Outcome {
+            solution: self.solution,
+            input: self.input,
+            run,
+            expected: self.answers.map(|path| Answers::read(&path)),
         }
     }
 }
 
-struct Solution {
-    day: u32,
-    path: PathBuf,
-    wrapper: fn(String) -> (String, String),
+struct Outcome<'a> {
+    solution: &'a Solution,
+    input: PathBuf,
+    run: Result<Run, Failure>,
+    expected: Option<Answers>,
+}
+
+impl Outcome<'_> {
+    // Number of answers which differ from the expected ones. An invalid input counts as a single
+    // failure.
+    fn failures(&self) -> usize {
+        match (&self.run, &self.expected) {
+            (Ok(run), Some(expected)) => expected.failures(run),
+            (Err(Failure::InvalidInput(_)), Some(_)) => 1,
+            _ => 0,
+        }
+    }
+}
+
+fn records(outcomes: &[Outcome]) -> Vec<Record> {
+    outcomes
+        .iter()
+        .map(|outcome| {
+            let Solution { day, title, .. } = *outcome.solution;
+            let run = outcome.run.as_ref().map_err(Failure::to_string);
+            Record::new(day, title, outcome.input.display().to_string(), run)
+        })
+        .collect()
+}
+
+fn print_outcomes(outcomes: &[Outcome], bench: bool) {
+    let mut total = Duration::ZERO;
+
+    for Outcome {
+        solution,
+        input,
+        run,
+        expected,
+    } in outcomes
+    {
+        let Solution { day, title, .. } = solution;
+
+        match run {
+            Ok(run) => {
+                println!("Day {day:02}: {title}");
+                print_answers(run, expected.as_ref());
+
+                if bench {
+                    total += print_bench(run);
+                } else {
+                    total += print_run(run);
+                }
+            }
+            Err(Failure::MissingInput) => {
+                eprintln!("Day {day:02}: {title}");
+                eprintln!("    Missing input in {}", input.display());
+            }
+            Err(Failure::Unreadable(e)) => {
+                eprintln!("Day {day:02}: {title}");
+                eprintln!("    Unable to read input in {}: {e}", input.display());
+            }
+            Err(Failure::InvalidInput(e)) => {
+                eprintln!("Day {day:02}: {title}");
+                eprintln!("    Invalid input in {}: {e}", input.display());
+            }
+        }
+    }
+
+    if bench {
+        println!("Total (mean): {total:.1?}");
+    } else {
+        println!("Total: {total:.1?}");
+    }
 }
 
-macro_rules! solution {
-    ($day:tt) => {{
-        let day = stringify!($day);
-        let path = Path::new("input").join(day).with_extension("txt");
+// Prints the outcomes of a single solution over multiple inputs as a table, with one row per
+// input.
+fn print_table(outcomes: &[Outcome]) {
+    const HEADER: [&str; 6] = [
+        "File",
+        "Part 1",
+        "Part 2",
+        "Parse time",
+        "Part 1 time",
+        "Part 2 time",
+    ];
+
+    let Some(Outcome { solution, .. }) = outcomes.first() else {
+        return;
+    };
+    println!("Day {:02}: {}", solution.day, solution.title);
+
+    let rows: Vec<_> = outcomes
+        .iter()
+        .map(|outcome| {
+            let file = outcome.input.file_name().unwrap_or_default();
+            let file = file.to_string_lossy().into_owned();
+
+            let run = match &outcome.run {
+                Ok(run) => run,
+                Err(e) => return Err((file, e)),
+            };
 
-        let wrapper = |data: String| {
-            use solutions::$day::{parse, part1, part2};
+            let mean = |durations: &[Duration]| {
+                Stats::new(durations).map_or(String::new(), |s| format!("{:.1?}", s.mean))
+            };
+            let answer = |answer: &Option<String>, expected: Option<&Option<String>>| match (
+                answer, expected,
+            ) {
+                (Some(answer), Some(expected)) => {
+                    format!("{answer} {}", Verdict::new(expected.as_deref(), answer))
+                }
+                (Some(answer), None) => answer.clone(),
+                (None, _) => String::new(),
+            };
+            let expected = outcome.expected.as_ref();
 
-            let input = parse(&data);
-            let part1 = part1(&input);
-            let part2 = part2(&input);
+            Ok([
+                file,
+                answer(&run.part1, expected.map(|e| &e.part1)),
+                answer(&run.part2, expected.map(|e| &e.part2)),
+                mean(&run.timings.parse),
+                mean(&run.timings.part1),
+                mean(&run.timings.part2),
+            ])
+        })
+        .collect();
 
-            (part1.to_string(), part2.to_string())
+    let mut widths = HEADER.map(str::len);
+    for row in rows.iter().flatten() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: [&str; 6]| {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("    {}", cells.join("  ").trim_end());
+    };
+
+    print_row(HEADER);
+    for row in &rows {
+        match row {
+            Ok(row) => print_row(row.each_ref().map(String::as_str)),
+            Err((file, e)) => println!("    {file:<width$}  {e}", width = widths[0]),
+        }
+    }
+}
+
+// Prints the answers of a run, compared to the expected ones if any.
+fn print_answers(run: &Run, expected: Option<&Answers>) {
+    let answers = [&run.part1, &run.part2];
+
+    let Some(expected) = expected else {
+        for (part, answer) in (1..).zip(answers) {
+            if let Some(answer) = answer {
+                println!("    Part {part}: {answer}");
+            }
+        }
+        return;
+    };
+
+    let expected = [&expected.part1, &expected.part2];
+    for (part, (answer, expected)) in (1..).zip(answers.into_iter().zip(expected)) {
+        let Some(answer) = answer else {
+            continue;
         };
+        match Verdict::new(expected.as_deref(), answer) {
+            verdict @ Verdict::Fail => {
+                let expected = expected.as_deref().unwrap_or_default();
+                println!("    Part {part}: {answer} {verdict} (expected {expected})");
+            }
+            verdict => println!("    Part {part}: {answer} {verdict}"),
+        }
+    }
+}
 
-        Solution {
-            day: day.unsigned(),
-            path,
-            wrapper,
-        }
-    }};
-}
-
-fn solutions() -> Vec<Solution> {
-    vec![
-        solution!(day01),
-        solution!(day02),
-        solution!(day03),
-        solution!(day04),
-        solution!(day05),
-        solution!(day06),
-        solution!(day07),
-        solution!(day08),
-        solution!(day09),
-        solution!(day10),
-        solution!(day11),
+// Prints the durations of a single run, and returns their sum.
+fn print_run(run: &Run) -> Duration {
+    let timings = &run.timings;
+
+    let phases: Vec<_> = [
+        ("parse", &timings.parse),
+        ("part 1", &timings.part1),
+        ("part 2", &timings.part2),
     ]
+    .into_iter()
+    .filter_map(|(phase, durations)| Some((phase, *durations.first()?)))
+    .collect();
+
+    let summary: Vec<_> = phases
+        .iter()
+        .map(|(phase, duration)| format!("{phase} {duration:.1?}"))
+        .collect();
+    println!("    Time:   {}", summary.join(", "));
+
+    phases.iter().map(|(_, duration)| duration).sum()
+}
+
+// Prints timing statistics of repeated runs, and returns the sum of the mean durations of each
+// phase.
+fn print_bench(run: &Run) -> Duration {
+    let timings = &run.timings;
+
+    [
+        ("Parse: ", &timings.parse),
+        ("Part 1:", &timings.part1),
+        ("Part 2:", &timings.part2),
+    ]
+    .into_iter()
+    .filter_map(|(phase, durations)| Some((phase, Stats::new(durations)?)))
+    .map(|(phase, stats)| {
+        let Stats {
+            min,
+            median,
+            mean,
+            max,
+        } = stats;
+        println!("    {phase} min {min:.1?}, median {median:.1?}, mean {mean:.1?}, max {max:.1?}");
+        mean
+    })
+    .sum()
+}
+
+struct Options {
+    // Selected days, all of them if empty.
+    days: Vec<u32>,
+    config: Config,
+    bench: bool,
+    // Size of the thread pool, if days run in parallel.
+    threads: Option<usize>,
+    verify: bool,
+    format: Format,
+    // Explicit path to the input, or "-" for stdin.
+    input: Option<String>,
+    // Directory of inputs to run a single day against.
+    batch: Option<PathBuf>,
+}
+
+impl Options {
+    fn parse() -> Result<Self, String> {
+        let mut options = Options {
+            days: Vec::new(),
+            config: Config::default(),
+            bench: false,
+            threads: None,
+            verify: false,
+            format: Format::Text,
+            input: None,
+            batch: None,
+        };
+
+        let mut all = false;
+
+        let mut args = args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--bench" => {
+                    options.bench = true;
+                    options.config.runs = args
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|n| *n > 0)
+                        .ok_or("--bench expects a positive number of runs")?;
+                }
+                "--verify" => options.verify = true,
+                "--format" => {
+                    options.format = match args.next().as_deref() {
+                        Some("text") => Format::Text,
+                        Some("json") => Format::Json,
+                        Some("csv") => Format::Csv,
+                        _ => return Err("--format expects one of text, json, csv".to_owned()),
+                    };
+                }
+                "--input" => {
+                    options.input = Some(
+                        args.next()
+                            .ok_or("--input expects a path, or - for stdin")?,
+                    );
+                }
+                "--batch" => {
+                    options.batch = Some(args.next().ok_or("--batch expects a directory")?.into());
+                }
+                "--part" => {
+                    options.config.parts = match args.next().as_deref() {
+                        Some("1") => Parts::Part1,
+                        Some("2") => Parts::Part2,
+                        _ => return Err("--part expects either 1 or 2".to_owned()),
+                    };
+                }
+                "--all" => all = true,
+                "--parallel" => {
+                    let threads = available_parallelism().map_or(1, NonZeroUsize::get);
+                    options.threads = Some(threads);
+                }
+                "--threads" => {
+                    options.threads = Some(
+                        args.next()
+                            .and_then(|n| n.parse().ok())
+                            .filter(|n| *n > 0)
+                            .ok_or("--threads expects a positive number of threads")?,
+                    );
+                }
+                opt if opt.starts_with("--") => return Err(format!("Unknown option \"{opt}\"")),
+                days => options.days.extend(parse_days(days)?),
+            }
+        }
+
+        if all {
+            options.days.clear();
+        }
+
+        if options.input.is_some() && options.days.len() != 1 {
+            return Err("--input requires a single day".to_owned());
+        }
+        if options.batch.is_some() && options.days.len() != 1 {
+            return Err("--batch requires a single day".to_owned());
+        }
+        if options.verify && options.input.as_deref() == Some("-") {
+            return Err("--verify requires an input file, not stdin".to_owned());
+        }
+        if options.batch.is_some() && options.input.is_some() {
+            return Err("--batch and --input are mutually exclusive".to_owned());
+        }
+
+        Ok(options)
+    }
+}
+
+// Parses a list of days and ranges of days, such as "1,4,9" or "3-7".
+fn parse_days(arg: &str) -> Result<Vec<u32>, String> {
+    let day = |d: &str| d.parse::<u32>().map_err(|_| format!("Invalid day \"{d}\""));
+
+    let mut days = Vec::new();
+    for item in arg.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (day(first)?, day(last)?);
+                if first > last {
+                    return Err(format!("Invalid range of days \"{item}\""));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(day(item)?),
+        }
+    }
+    Ok(days)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+// Reason why a solution could not produce answers.
+enum Failure {
+    MissingInput,
+    Unreadable(io::Error),
+    InvalidInput(Error),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::MissingInput => f.write_str("missing input"),
+            Failure::Unreadable(e) => write!(f, "unreadable input: {e}"),
+            Failure::InvalidInput(e) => write!(f, "invalid input: {e}"),
+        }
+    }
 }
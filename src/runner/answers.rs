@@ -0,0 +1,93 @@
+use crate::Run;
+use std::fmt::{self, Display, Formatter};
+use std::fs::read_to_string;
+use std::path::Path;
+
+/// Expected answers to the puzzle of a day, as stored in an answers file.
+///
+/// The file contains the answer to part 1 on its first line, and the answer to part 2 on its
+/// second line. Missing or empty lines denote unknown answers.
+#[derive(Default)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(data: &str) -> Self {
+        let mut lines = data
+            .lines()
+            .map(str::trim)
+            .map(|l| (!l.is_empty()).then(|| l.to_owned()));
+
+        Self {
+            part1: lines.next().flatten(),
+            part2: lines.next().flatten(),
+        }
+    }
+
+    // A missing file is equivalent to a file with no known answer.
+    pub fn read(path: &Path) -> Self {
+        read_to_string(path)
+            .map(|data| Self::parse(&data))
+            .unwrap_or_default()
+    }
+
+    // Number of answers of the given run which differ from the expected ones.
+    pub fn failures(&self, run: &Run) -> usize {
+        [(&self.part1, &run.part1), (&self.part2, &run.part2)]
+            .into_iter()
+            .filter_map(|(expected, answer)| {
+                Some(Verdict::new(expected.as_deref(), answer.as_ref()?))
+            })
+            .filter(|verdict| *verdict == Verdict::Fail)
+            .count()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&str>, actual: &str) -> Self {
+        match expected {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+        })
+    }
+}
+
+#[test]
+fn verdicts() {
+    let answers = Answers::parse("1928\n\n");
+    assert_eq!(answers.part1.as_deref(), Some("1928"));
+    assert_eq!(answers.part2, None);
+
+    assert_eq!(
+        Verdict::new(answers.part1.as_deref(), "1928"),
+        Verdict::Pass
+    );
+    assert_eq!(
+        Verdict::new(answers.part1.as_deref(), "2858"),
+        Verdict::Fail
+    );
+    assert_eq!(
+        Verdict::new(answers.part2.as_deref(), "2858"),
+        Verdict::Unknown
+    );
+}
@@ -0,0 +1,159 @@
+use crate::runner::timing::Stats;
+use crate::Run;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Outcome of a solution for a given input, in a shape suitable for machine-readable reports.
+pub struct Record {
+    pub day: u32,
+    pub title: &'static str,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    // Mean durations over all runs.
+    pub parse: Option<Duration>,
+    pub part1_time: Option<Duration>,
+    pub part2_time: Option<Duration>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(day: u32, title: &'static str, input: String, run: Result<&Run, String>) -> Self {
+        let mean = |durations: &[Duration]| Stats::new(durations).map(|s| s.mean);
+
+        match run {
+            Ok(run) => Self {
+                day,
+                title,
+                input,
+                part1: run.part1.clone(),
+                part2: run.part2.clone(),
+                parse: mean(&run.timings.parse),
+                part1_time: mean(&run.timings.part1),
+                part2_time: mean(&run.timings.part2),
+                error: None,
+            },
+            Err(error) => Self {
+                day,
+                title,
+                input,
+                part1: None,
+                part2: None,
+                parse: None,
+                part1_time: None,
+                part2_time: None,
+                error: Some(error),
+            },
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let string = |s: &Option<String>| s.as_deref().map_or("null".to_owned(), json_string);
+        let nanos = |d: Option<Duration>| d.map_or("null".to_owned(), |d| d.as_nanos().to_string());
+
+        format!(
+            "{{\"day\":{},\"title\":{},\"input\":{},\"part1\":{},\"part2\":{},\
+            \"parse_ns\":{},\"part1_ns\":{},\"part2_ns\":{},\"error\":{}}}",
+            self.day,
+            json_string(self.title),
+            json_string(&self.input),
+            string(&self.part1),
+            string(&self.part2),
+            nanos(self.parse),
+            nanos(self.part1_time),
+            nanos(self.part2_time),
+            string(&self.error),
+        )
+    }
+
+    fn to_csv(&self) -> String {
+        let string = |s: &Option<String>| s.as_deref().map_or(String::new(), csv_field);
+        let nanos = |d: Option<Duration>| d.map_or(String::new(), |d| d.as_nanos().to_string());
+
+        [
+            self.day.to_string(),
+            csv_field(self.title),
+            csv_field(&self.input),
+            string(&self.part1),
+            string(&self.part2),
+            nanos(self.parse),
+            nanos(self.part1_time),
+            nanos(self.part2_time),
+            string(&self.error),
+        ]
+        .join(",")
+    }
+}
+
+// Answers are emitted as strings, because some of them exceed the range of integers which JSON
+// parsers can represent exactly.
+pub fn json(records: &[Record]) -> String {
+    let records: Vec<_> = records.iter().map(|r| r.to_json()).collect();
+    if records.is_empty() {
+        return "[]\n".to_owned();
+    }
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+pub fn csv(records: &[Record]) -> String {
+    let mut out = "day,title,input,part1,part2,parse_ns,part1_ns,part2_ns,error\n".to_owned();
+    for r in records {
+        out.push_str(&r.to_csv());
+        out.push('\n');
+    }
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[test]
+fn escaping() {
+    let record = Record::new(
+        3,
+        "Mull It Over",
+        "input/day03.txt".to_owned(),
+        Err("line 1, column 2: expected \"mul\", found ','".to_owned()),
+    );
+    assert_eq!(
+        json(&[record]),
+        "[\n{\"day\":3,\"title\":\"Mull It Over\",\"input\":\"input/day03.txt\",\
+        \"part1\":null,\"part2\":null,\"parse_ns\":null,\"part1_ns\":null,\"part2_ns\":null,\
+        \"error\":\"line 1, column 2: expected \\\"mul\\\", found ','\"}\n]\n"
+    );
+
+    let record = Record::new(
+        3,
+        "Mull It Over",
+        "input/day03.txt".to_owned(),
+        Err("line 1, column 2: expected \"mul\", found ','".to_owned()),
+    );
+    assert_eq!(
+        csv(&[record]),
+        "day,title,input,part1,part2,parse_ns,part1_ns,part2_ns,error\n\
+        3,Mull It Over,input/day03.txt,,,,,,\"line 1, column 2: expected \"\"mul\"\", found ','\"\n"
+    );
+}
@@ -0,0 +1,67 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Durations of each phase of a solution, one per run.
+#[derive(Default)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+/// Summary of the durations of a phase over multiple runs.
+#[derive(Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(durations: &[Duration]) -> Option<Self> {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+
+        let (min, max) = (*sorted.first()?, *sorted.last()?);
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+
+        Some(Self {
+            min,
+            median,
+            mean,
+            max,
+        })
+    }
+}
+
+// Runs f at least once, and returns the result of the last run along with the duration of each run.
+pub fn repeat<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Vec<Duration>) {
+    let mut durations = Vec::with_capacity(runs);
+    loop {
+        let start = Instant::now();
+        let res = black_box(f());
+        durations.push(start.elapsed());
+
+        if durations.len() >= runs {
+            break (res, durations);
+        }
+    }
+}
+
+#[test]
+fn stats() {
+    let durations = [4, 1, 3, 8].map(Duration::from_millis);
+    let stats = Stats::new(&durations).unwrap();
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(3500));
+    assert_eq!(stats.mean, Duration::from_millis(4));
+    assert_eq!(stats.max, Duration::from_millis(8));
+    assert!(Stats::new(&[]).is_none());
+}
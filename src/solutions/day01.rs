@@ -1,6 +1,12 @@
+use crate::day::impl_day;
+use crate::error::Error;
 use crate::util::parse::ParseOps;
 use std::collections::HashMap;
 
+pub struct Day01;
+
+impl_day!(Day01, Locations, 1, "Historian Hysteria");
+
 #[derive(Clone)]
 pub struct Locations {
     left: Vec<u32>,
@@ -21,14 +27,20 @@ impl Locations {
     }
 }
 
-pub fn parse(input: &str) -> Locations {
+pub fn parse(input: &str) -> Result<Locations, Error> {
     let mut locs = Locations::new();
-    input.lines().for_each(|l| {
-        let mut loc_iter = l.iter_unsigned();
-        locs.left.push(loc_iter.next().unwrap());
-        locs.right.push(loc_iter.next().unwrap());
-    });
-    locs
+    for (j, l) in input.lines().enumerate() {
+        let ids: Vec<u32> = l
+            .iter_unsigned()
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.below(j))?;
+        let [left, right, ..] = ids[..] else {
+            return Err(Error::new(j + 1, l.len() + 1, "expected two location IDs"));
+        };
+        locs.left.push(left);
+        locs.right.push(right);
+    }
+    Ok(locs)
 }
 
 pub fn part1(locs: &Locations) -> u32 {
@@ -62,7 +74,7 @@ fn sample_input() {
         3   9\n\
         3   3\n\
         ";
-    let locs = parse(input);
+    let locs = parse(input).unwrap();
     assert_eq!(part1(&locs), 11);
     assert_eq!(part2(&locs), 31);
 }
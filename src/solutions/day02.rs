@@ -1,13 +1,27 @@
+use crate::day::impl_day;
+use crate::error::Error;
 use crate::util::parse::ParseOps;
 use std::cmp::Ordering;
 use std::cmp::Ordering::{Equal, Greater, Less};
 
-pub fn parse(input: &str) -> Vec<Vec<u32>> {
+pub struct Day02;
+
+impl_day!(Day02, Vec<Vec<u32>>, 2, "Red-Nosed Reports");
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, Error> {
     let mut reports = Vec::new();
-    input
-        .lines()
-        .for_each(|l| reports.push(l.iter_unsigned().collect()));
-    reports
+    for (j, l) in input.lines().enumerate() {
+        let levels: Vec<u32> = l
+            .iter_unsigned()
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.below(j))?;
+        // part2() infers the direction of a report from its first 3 levels.
+        if levels.len() < 3 {
+            return Err(Error::new(j + 1, l.len() + 1, "expected at least 3 levels"));
+        }
+        reports.push(levels);
+    }
+    Ok(reports)
 }
 
 pub fn part1(reports: &[Vec<u32>]) -> usize {
@@ -92,7 +106,7 @@ fn sample_input() {
         8 6 4 4 1\n\
         1 3 6 7 9\n\
         ";
-    let locs = parse(input);
+    let locs = parse(input).unwrap();
     assert_eq!(part1(&locs), 2);
     assert_eq!(part2(&locs), 4);
 }
@@ -102,7 +116,7 @@ fn first_two_levels_are_equal() {
     let input = "\
         3 3 6 7 9\n\
         ";
-    let locs = parse(input);
+    let locs = parse(input).unwrap();
     assert_eq!(part1(&locs), 0);
     assert_eq!(part2(&locs), 1);
 }
@@ -112,7 +126,7 @@ fn first_two_levels_are_far_apart() {
     let input = "\
         1 5 6 7 9\n\
         ";
-    let locs = parse(input);
+    let locs = parse(input).unwrap();
     assert_eq!(part1(&locs), 0);
     assert_eq!(part2(&locs), 1);
 }
@@ -123,7 +137,7 @@ fn increases_direction_changes_after_first_level() {
         9 3 6 7 8\n\
         1 7 5 3 2\n\
         ";
-    let locs = parse(input);
+    let locs = parse(input).unwrap();
     assert_eq!(part1(&locs), 0);
     assert_eq!(part2(&locs), 2);
 }
@@ -134,7 +148,7 @@ fn increase_direction_changes_after_second_level() {
         6 8 4 3 1\n\
         4 2 6 7 9\n\
         ";
-    let locs = parse(input);
+    let locs = parse(input).unwrap();
     assert_eq!(part1(&locs), 0);
     assert_eq!(part2(&locs), 2);
 }
@@ -145,7 +159,7 @@ fn increase_direction_unclear() {
         3 1 2 2\n\
         1 2 3 2\n\
         ";
-    let locs = parse(input);
+    let locs = parse(input).unwrap();
     assert_eq!(part1(&locs), 0);
     assert_eq!(part2(&locs), 0);
 }
@@ -1,7 +1,14 @@
+use crate::day::impl_day;
+use crate::error::Error;
 use std::str::Bytes;
 
-pub fn parse(input: &str) -> Vec<Mul> {
-    input.iter_mul().collect()
+pub struct Day03;
+
+impl_day!(Day03, Vec<Mul>, 3, "Mull It Over");
+
+// Corrupted instructions are part of the puzzle, so parsing never fails.
+pub fn parse(input: &str) -> Result<Vec<Mul>, Error> {
+    Ok(input.iter_mul().collect())
 }
 
 pub fn part1(muls: &[Mul]) -> u32 {
@@ -37,11 +44,11 @@ impl From<Vec<u32>> for Mul {
 }
 
 trait ParseOps {
-    fn iter_mul(&self) -> ParseMul;
+    fn iter_mul(&self) -> ParseMul<'_>;
 }
 
 impl ParseOps for &str {
-    fn iter_mul(&self) -> ParseMul {
+    fn iter_mul(&self) -> ParseMul<'_> {
         ParseMul {
             bytes: self.bytes(),
         }
@@ -85,22 +92,26 @@ fn try_mul(bytes: &mut Bytes) -> Option<Mul> {
                     d @ b'0'..=b'9' => {
                         digits.push(d.into());
                     }
-                    b',' => {
-                        // reached "mul(nnn,"
-                        if numbers.is_empty() {
-                            // safe because input does not contain any "mul(,"
-                            numbers.push(digits.parse().unwrap());
-                            digits.clear();
-                        // reached "mul(nnn,nnn,"
-                        } else {
+                    // reached "mul(nnn,"
+                    b',' if numbers.is_empty() => {
+                        // "mul(," is not a valid instruction
+                        let Ok(n) = digits.parse() else {
                             break;
-                        }
+                        };
+                        numbers.push(n);
+                        digits.clear();
+                    }
+                    // reached "mul(nnn,nnn,"
+                    b',' => {
+                        break;
                     }
                     b')' => {
                         // reached "mul(nnn,nnn)", but not "mul(nnn)"
                         if !numbers.is_empty() {
-                            // safe because input does not contain any "mul(nnn,)"
-                            numbers.push(digits.parse().unwrap());
+                            // "mul(nnn,)" is not a valid instruction
+                            if let Ok(n) = digits.parse() {
+                                numbers.push(n);
+                            }
                         }
                         break;
                     }
@@ -123,7 +134,7 @@ fn sample_input() {
     let input = "\
         xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n\
         ";
-    let res = parse(input);
+    let res = parse(input).unwrap();
     assert_eq!(part1(&res), 161);
     assert_eq!(part2(&res), 48);
 }
@@ -134,7 +145,16 @@ fn mul_in_mul() {
         xmul(2,mul(8,5)\n\
         xmul(mul(8,5)\n\
         ";
-    let res = parse(input);
+    let res = parse(input).unwrap();
     assert_eq!(part1(&res), 80);
     assert_eq!(part2(&res), 80);
 }
+
+#[test]
+fn mul_missing_operand() {
+    let input = "\
+        mul(,5)mul(5,)mul(2,3)\n\
+        ";
+    let res = parse(input).unwrap();
+    assert_eq!(part1(&res), 6);
+}
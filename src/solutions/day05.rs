@@ -1,24 +1,49 @@
+use crate::day::impl_day;
+use crate::error::Error;
 use crate::util::parse::ParseOps;
 use std::cmp::Ordering;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 
-pub fn parse(input: &str) -> (Updates, OrderingRules) {
-    let (section_one, section_two) = input.split_once("\n\n").unwrap();
+pub struct Day05;
+
+impl_day!(Day05, (Updates, OrderingRules), 5, "Print Queue");
+
+pub fn parse(input: &str) -> Result<(Updates, OrderingRules), Error> {
+    let Some((section_one, section_two)) = input.split_once("\n\n") else {
+        return Err(Error::new(
+            input.lines().count() + 1,
+            1,
+            "expected an empty line between ordering rules and updates",
+        ));
+    };
 
     let (mut updates, mut ordering_rules) = (Updates::new(), OrderingRules::new());
 
-    for line in section_one.lines() {
-        let mut pages_iter = line.iter_unsigned();
-        let (before, after) = (pages_iter.next().unwrap(), pages_iter.next().unwrap());
+    let section_one_len = section_one.lines().count();
+    for (j, line) in section_one.lines().enumerate() {
+        let pages: Vec<u32> = line
+            .iter_unsigned()
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.below(j))?;
+        let [before, after, ..] = pages[..] else {
+            return Err(Error::new(j + 1, line.len() + 1, "expected two pages"));
+        };
         ordering_rules.entry(before).or_default().insert(after);
     }
 
-    for l in section_two.lines() {
-        updates.push(l.iter_unsigned().collect());
+    for (j, l) in section_two.lines().enumerate() {
+        let pages: Vec<u32> = l
+            .iter_unsigned()
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.below(section_one_len + j + 1))?;
+        if pages.is_empty() {
+            return Err(Error::new(section_one_len + j + 2, 1, "expected pages"));
+        }
+        updates.push(pages);
     }
 
-    (updates, ordering_rules)
+    Ok((updates, ordering_rules))
 }
 
 pub fn part1(sections: &(Updates, OrderingRules)) -> u32 {
@@ -67,7 +92,7 @@ impl OrderingRules {
         Self { r: HashMap::new() }
     }
 
-    fn entry(&mut self, key: u32) -> Entry<u32, HashSet<u32>> {
+    fn entry(&mut self, key: u32) -> Entry<'_, u32, HashSet<u32>> {
         self.r.entry(key)
     }
 
@@ -120,7 +145,7 @@ fn sample_input() {
         61,13,29\n\
         97,13,75,29,47\n\
         ";
-    let out = parse(input);
+    let out = parse(input).unwrap();
     assert_eq!(part1(&out), 143);
     assert_eq!(part2(&out), 123);
 }
@@ -458,7 +483,7 @@ fn contains_loop() {
         \n\
         11\n\
         ";
-    let out = parse(input);
+    let out = parse(input).unwrap();
     assert_eq!(part1(&out), 11);
     assert_eq!(part2(&out), 0);
 }
@@ -1,22 +1,33 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::grid::Grid;
+use crate::util::point::{Direction, Point};
 use std::collections::{HashMap, HashSet};
 
-pub fn parse(input: &str) -> (Grid, usize) {
-    let input_unwrapped: &str = &input.replace('\n', "");
+pub struct Day06;
 
-    let grid = Grid {
-        size: (input_unwrapped.len() as f32).sqrt() as usize,
-        obstacles: input_unwrapped
-            .match_indices('#')
-            .map(|(pos, _)| pos)
-            .collect(),
+impl_day!(Day06, (Grid<bool>, Point), 6, "Guard Gallivant");
+
+pub fn parse(input: &str) -> Result<(Grid<bool>, Point), Error> {
+    let map = Grid::parse(input)?;
+
+    for (j, line) in input.lines().enumerate() {
+        if let Some(i) = line.find(|c| !matches!(c, '.' | '#' | '^')) {
+            return Err(Error::new(j + 1, i + 1, "expected one of '.', '#', '^'"));
+        }
+    }
+
+    let Some(start) = map.find(&b'^') else {
+        return Err(Error::new(map.height + 1, 1, "missing guard position '^'"));
     };
 
-    let start = input_unwrapped.find('^').unwrap();
+    // Whether each position holds an obstacle.
+    let grid = map.map(|c| *c == b'#');
 
-    (grid, start)
+    Ok((grid, start))
 }
 
-pub fn part1(r#in: &(Grid, usize)) -> usize {
+pub fn part1(r#in: &(Grid<bool>, Point)) -> usize {
     let (grid, start) = r#in;
 
     let mut visits = HashSet::new();
@@ -24,7 +35,7 @@ pub fn part1(r#in: &(Grid, usize)) -> usize {
 
     let mut cur_pos = *start;
     let mut cur_direction = Direction::Up;
-    while let Some((pos, d)) = grid.step(&cur_pos, &cur_direction) {
+    while let Some((pos, d)) = step(grid, &cur_pos, &cur_direction) {
         (cur_pos, cur_direction) = (pos, d);
         visits.insert(cur_pos);
     }
@@ -32,7 +43,7 @@ pub fn part1(r#in: &(Grid, usize)) -> usize {
     visits.len()
 }
 
-pub fn part2(r#in: &(Grid, usize)) -> usize {
+pub fn part2(r#in: &(Grid<bool>, Point)) -> usize {
     let (grid, start) = r#in;
 
     let mut visits = HashSet::new();
@@ -43,7 +54,7 @@ pub fn part2(r#in: &(Grid, usize)) -> usize {
     let mut cur_direction = Direction::Up;
     visits.insert((cur_pos, cur_direction));
     visits_ordered.push((cur_pos, cur_direction));
-    while let Some((pos, d)) = grid.step(&cur_pos, &cur_direction) {
+    while let Some((pos, d)) = step(grid, &cur_pos, &cur_direction) {
         (cur_pos, cur_direction) = (pos, d);
         visits.insert((cur_pos, cur_direction));
         visits_ordered.push((cur_pos, cur_direction));
@@ -77,102 +88,28 @@ pub fn part2(r#in: &(Grid, usize)) -> usize {
             let mut cur_direction = prev_direction;
 
             let mut is_loop = false;
-            grid.obstacles.insert(new_obs);
-            while let Some((pos, d)) = grid.step(&cur_pos, &cur_direction) {
+            grid.set(new_obs, true);
+            while let Some((pos, d)) = step(&grid, &cur_pos, &cur_direction) {
                 if !visits.insert((pos, d)) {
                     is_loop = true;
                     break;
                 }
                 (cur_pos, cur_direction) = (pos, d);
             }
-            grid.obstacles.remove(&new_obs);
+            grid.set(new_obs, false);
             is_loop
         })
         .count()
 }
 
-#[derive(Clone)]
-pub struct Grid {
-    size: usize,
-    obstacles: HashSet<usize>,
-}
-
-impl Grid {
-    fn step(&self, cur: &usize, d: &Direction) -> Option<(usize, Direction)> {
-        let (cur_x, cur_y) = self.coordinates(cur);
-        let nxt = match d {
-            Direction::Up => {
-                if cur_y == 0 {
-                    return None;
-                }
-                self.position((cur_x, cur_y - 1))
-            }
-            Direction::Right => {
-                if cur_x == self.size - 1 {
-                    return None;
-                }
-                self.position((cur_x + 1, cur_y))
-            }
-            Direction::Down => {
-                if cur_y == self.size - 1 {
-                    return None;
-                }
-                self.position((cur_x, cur_y + 1))
-            }
-            Direction::Left => {
-                if cur_x == 0 {
-                    return None;
-                }
-                self.position((cur_x - 1, cur_y))
-            }
-        };
-
-        if self.obstacles.contains(&nxt) {
-            self.step(cur, &d.next())
-        } else {
-            Some((nxt, *d))
-        }
-    }
-
-    // position -> (x,y) coordinates
-    fn coordinates(&self, pos: &usize) -> (usize, usize) {
-        (pos % self.size, pos / self.size)
-    }
-
-    // (x,y) coordinates -> position
-    fn position(&self, coord: (usize, usize)) -> usize {
-        coord.0 + coord.1 * self.size
-    }
-}
-
-#[derive(Clone, Copy, Hash, Eq, PartialEq)]
-#[repr(u8)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
+// Next position and direction of the guard, unless it leaves the map.
+fn step(grid: &Grid<bool>, cur: &Point, d: &Direction) -> Option<(Point, Direction)> {
+    let nxt = *cur + d.delta();
 
-impl Direction {
-    fn next(&self) -> Self {
-        ((*self as u8 + 1_u8) % 4).try_into().unwrap()
-    }
-}
-
-impl TryFrom<u8> for Direction {
-    type Error = ();
-
-    // https://doc.rust-lang.org/reference/items/enumerations.html#accessing-discriminant
-    // https://internals.rust-lang.org/t/pre-rfc-enum-from-integer/6348/21
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            v if v == Direction::Up as u8 => Ok(Direction::Up),
-            v if v == Direction::Right as u8 => Ok(Direction::Right),
-            v if v == Direction::Down as u8 => Ok(Direction::Down),
-            v if v == Direction::Left as u8 => Ok(Direction::Left),
-            _ => todo!(),
-        }
+    if *grid.get(nxt)? {
+        step(grid, cur, &d.turn_right())
+    } else {
+        Some((nxt, *d))
     }
 }
 
@@ -190,7 +127,20 @@ fn sample_input() {
         #.........\n\
         ......#...\n\
         ";
-    let out = parse(input);
+    let out = parse(input).unwrap();
     assert_eq!(part1(&out), 41);
     assert_eq!(part2(&out), 6);
 }
+
+#[test]
+fn missing_guard() {
+    let input = "\
+        ..#\n\
+        ...\n\
+        #..\n\
+        ";
+    assert_eq!(
+        parse(input).err(),
+        Some(Error::new(4, 1, "missing guard position '^'"))
+    );
+}
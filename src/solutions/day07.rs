@@ -1,12 +1,29 @@
+use crate::day::impl_day;
+use crate::error::Error;
 use crate::util::parse::ParseOps;
 use std::collections::VecDeque;
 
-pub fn parse(input: &str) -> Vec<(u64, Vec<u64>)> {
+pub struct Day07;
+
+impl_day!(Day07, Vec<(u64, Vec<u64>)>, 7, "Bridge Repair");
+
+pub fn parse(input: &str) -> Result<Vec<(u64, Vec<u64>)>, Error> {
     input
         .lines()
-        .map(|l| {
-            let mut iter = l.iter_unsigned();
-            (iter.next().unwrap(), iter.collect())
+        .enumerate()
+        .map(|(j, l)| {
+            let numbers: Vec<u64> = l
+                .iter_unsigned()
+                .collect::<Result<_, _>>()
+                .map_err(|e| e.below(j))?;
+            match numbers.split_first() {
+                Some((tval, numbers)) if !numbers.is_empty() => Ok((*tval, numbers.to_vec())),
+                _ => Err(Error::new(
+                    j + 1,
+                    l.len() + 1,
+                    "expected a test value and at least one number",
+                )),
+            }
         })
         .collect()
 }
@@ -88,7 +105,7 @@ fn sample_input() {
         21037: 9 7 18 13\n\
         292: 11 6 16 20\n\
         ";
-    let eqs = parse(input);
+    let eqs = parse(input).unwrap();
     assert_eq!(part1(&eqs), 3749);
     assert_eq!(part2(&eqs), 11387);
 }
@@ -99,7 +116,7 @@ fn extract_from_real_input() {
         127536599: 49 4 21 65 99\n\
         7: 15 15 15\n\
         ";
-    let eqs = parse(input);
+    let eqs = parse(input).unwrap();
     assert_eq!(part1(&eqs), 0);
     /* 49 * 4 || 21 x 65 || 99
      *    196 || 21 x 65 || 99
@@ -1,31 +1,35 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::grid::Grid;
+use crate::util::point::Point;
 use std::collections::{HashMap, HashSet};
 
-pub fn parse(input: &str) -> Grid {
-    let input_unwrapped = input.replace('\n', "");
-    let size = (input_unwrapped.len() as f32).sqrt() as usize;
+pub struct Day08;
 
-    let mut antennas_by_freq: HashMap<char, Vec<Point>> = HashMap::new();
-    input_unwrapped.chars().enumerate().for_each(|(pos, c)| {
-        if c.is_ascii_alphanumeric() {
-            antennas_by_freq
-                .entry(c)
-                .or_default()
-                .push(Point((pos % size) as i32, (pos / size) as i32))
+impl_day!(Day08, City, 8, "Resonant Collinearity");
+
+pub fn parse(input: &str) -> Result<City, Error> {
+    let map = Grid::parse(input)?;
+
+    let mut antennas_by_freq: HashMap<u8, Vec<Point>> = HashMap::new();
+    map.points().for_each(|p| {
+        if map[p].is_ascii_alphanumeric() {
+            antennas_by_freq.entry(map[p]).or_default().push(p)
         }
     });
     let antennas = antennas_by_freq.into_values().collect();
 
-    Grid { size, antennas }
+    Ok(City { map, antennas })
 }
 
-pub fn part1(g: &Grid) -> usize {
+pub fn part1(g: &City) -> usize {
     let mut antinodes = HashSet::new();
     g.antennas.iter().for_each(|antennas| {
         antennas.iter().enumerate().for_each(|(i, antenna)| {
             let rotation_points = antennas[..i].iter().chain(antennas[(i + 1)..].iter());
             rotation_points.for_each(|rp| {
-                let a = antenna.rotate_180(rp);
-                if g.contains(&a) {
+                let a = rotate_180(antenna, rp);
+                if g.map.contains(a) {
                     antinodes.insert(a);
                 }
             });
@@ -34,7 +38,7 @@ pub fn part1(g: &Grid) -> usize {
     antinodes.len()
 }
 
-pub fn part2(g: &Grid) -> usize {
+pub fn part2(g: &City) -> usize {
     let mut antinodes = HashSet::new();
     g.antennas.iter().for_each(|antennas| {
         antennas.iter().enumerate().for_each(|(i, antenna)| {
@@ -44,8 +48,8 @@ pub fn part2(g: &Grid) -> usize {
                 let mut antenna = *antenna;
                 let mut rp = *rp;
                 loop {
-                    let a = antenna.rotate_180(&rp);
-                    if !g.contains(&a) {
+                    let a = rotate_180(&antenna, &rp);
+                    if !g.map.contains(a) {
                         break;
                     }
                     antinodes.insert(a);
@@ -57,25 +61,14 @@ pub fn part2(g: &Grid) -> usize {
     antinodes.len()
 }
 
-pub struct Grid {
-    size: usize,
+pub struct City {
+    map: Grid<u8>,
     antennas: Vec<Vec<Point>>,
 }
 
-impl Grid {
-    fn contains(&self, p: &Point) -> bool {
-        let upper_bound = self.size as i32;
-        p.0 >= 0 && p.0 < upper_bound && p.1 >= 0 && p.1 < upper_bound
-    }
-}
-
-#[derive(Clone, Copy, Hash, Eq, PartialEq)]
-struct Point(i32, i32);
-
-impl Point {
-    fn rotate_180(&self, rp: &Point) -> Point {
-        Point(rp.0 + (rp.0 - self.0), rp.1 + (rp.1 - self.1))
-    }
+// Rotates the point by 180° around the rotation point.
+fn rotate_180(p: &Point, rp: &Point) -> Point {
+    *rp + (*p - *rp).rotate_180()
 }
 
 #[test]
@@ -94,7 +87,7 @@ fn sample_input() {
         ............\n\
         ............\n\
         ";
-    let out = parse(input);
+    let out = parse(input).unwrap();
     assert_eq!(part1(&out), 14);
     assert_eq!(part2(&out), 34);
 }
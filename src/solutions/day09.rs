@@ -1,22 +1,33 @@
+use crate::day::impl_day;
+use crate::error::Error;
 use std::cmp::Ordering;
 
-pub fn parse(input: &str) -> Vec<Block> {
-    input
+pub struct Day09;
+
+impl_day!(Day09, Vec<Block>, 9, "Disk Fragmenter");
+
+pub fn parse(input: &str) -> Result<Vec<Block>, Error> {
+    let diskmap = input
         .bytes()
         .enumerate()
         .filter_map(|(i, c)| match c {
             b'1'..=b'9' => {
                 let len = (c - b'0').into();
-                Some(if i % 2 == 0 {
+                Some(Ok(if i % 2 == 0 {
                     Block::File { id: i / 2, len }
                 } else {
                     Block::Free(len)
-                })
+                }))
             }
             b'0' | b'\n' => None,
-            _ => unimplemented!(),
+            _ => Some(Err(Error::new(1, i + 1, "expected a digit"))),
         })
-        .collect()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if diskmap.is_empty() {
+        return Err(Error::new(1, 1, "expected a disk map"));
+    }
+    Ok(diskmap)
 }
 
 pub fn part1(diskmap: &[Block]) -> usize {
@@ -161,7 +172,7 @@ fn sample_input() {
     let input = "\
         2333133121414131402\n\
         ";
-    let diskmap = parse(input);
+    let diskmap = parse(input).unwrap();
     assert_eq!(part1(&diskmap), 1928);
     assert_eq!(part2(&diskmap), 2858);
 }
@@ -171,7 +182,18 @@ fn trivial() {
     let input = "\
         12345\n\
         ";
-    let diskmap = parse(input);
+    let diskmap = parse(input).unwrap();
     assert_eq!(part1(&diskmap), 60);
     assert_eq!(part2(&diskmap), 132);
 }
+
+#[test]
+fn invalid_digit() {
+    let input = "\
+        12x45\n\
+        ";
+    assert_eq!(
+        parse(input).err(),
+        Some(Error::new(1, 3, "expected a digit"))
+    );
+}
@@ -1,42 +1,52 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::grid::Grid;
 use petgraph::visit::{Dfs, Visitable};
 use petgraph::{Directed, Graph};
 use std::collections::VecDeque;
 
-pub fn parse(input: &str) -> Topography {
-    let map_locs: Vec<u8> = input.replace('\n', "").bytes().collect();
-    let size = (map_locs.len() as f32).sqrt() as usize;
+pub struct Day10;
+
+impl_day!(Day10, Topography, 10, "Hoof It");
+
+pub fn parse(input: &str) -> Result<Topography, Error> {
+    let heights = Grid::parse(input)?;
+
+    for (j, line) in input.lines().enumerate() {
+        if let Some(i) = line.find(|c: char| !c.is_ascii_digit() && c != '.') {
+            return Err(Error::new(j + 1, i + 1, "expected a height or '.'"));
+        }
+    }
+
+    // Points of the grid are always within bounds.
+    let node = |p| heights.offset(p).unwrap();
 
     let mut trailheads = Vec::new();
 
-    let map = Graph::from_edges(
-        map_locs
-            .iter()
-            .enumerate()
-            .filter(|(_, height)| **height != b'.') // test inputs
-            .flat_map(|(i, height)| {
-                if *height == b'0' {
-                    trailheads.push(i);
+    // Every cell gets a node, including the ones without any edge, such as isolated trailheads.
+    let mut map = Graph::with_capacity(heights.width * heights.height, 0);
+    for _ in heights.points() {
+        map.add_node(());
+    }
+    map.extend_with_edges(
+        heights
+            .points()
+            .filter(|p| heights[*p] != b'.') // test inputs
+            .flat_map(|p| {
+                let height = heights[p];
+                if height == b'0' {
+                    trailheads.push(node(p));
                 }
 
-                let mut edges = Vec::new();
-                // ← → ↑ ↓
-                if i % size != 0 && map_locs[i - 1] == height + 1 {
-                    edges.push((i, i - 1));
-                }
-                if (i + 1) % size != 0 && map_locs[i + 1] == height + 1 {
-                    edges.push((i, i + 1));
-                }
-                if i >= size && map_locs[i - size] == height + 1 {
-                    edges.push((i, i - size));
-                }
-                if i + size <= size * size - 1 && map_locs[i + size] == height + 1 {
-                    edges.push((i, i + size));
-                }
-                edges
+                heights
+                    .neighbours4(p)
+                    .filter(|n| heights[*n] == height + 1)
+                    .map(|n| (node(p), node(n)))
+                    .collect::<Vec<_>>()
             }),
     );
 
-    Topography { map, trailheads }
+    Ok(Topography { map, trailheads })
 }
 
 pub fn part1(topo: &Topography) -> u32 {
@@ -113,7 +123,7 @@ fn sample_input_1() {
         8.....8\n\
         9.....9\n\
         ";
-    let topo = parse(input);
+    let topo = parse(input).unwrap();
     assert_eq!(part1(&topo), 2);
 }
 
@@ -128,7 +138,7 @@ fn sample_input_2() {
         876....\n\
         987....\n\
         ";
-    let topo = parse(input);
+    let topo = parse(input).unwrap();
     assert_eq!(part1(&topo), 4);
     assert_eq!(part2(&topo), 13);
 }
@@ -144,7 +154,7 @@ fn sample_input_3() {
         ...9..2\n\
         .....01\n\
         ";
-    let topo = parse(input);
+    let topo = parse(input).unwrap();
     assert_eq!(part1(&topo), 3);
 }
 
@@ -160,7 +170,7 @@ fn sample_input_4() {
         01329801\n\
         10456732\n\
         ";
-    let topo = parse(input);
+    let topo = parse(input).unwrap();
     assert_eq!(part1(&topo), 36);
     assert_eq!(part2(&topo), 81);
 }
@@ -176,7 +186,7 @@ fn sample_input_5() {
         ..8765.\n\
         ..9....\n\
         ";
-    let topo = parse(input);
+    let topo = parse(input).unwrap();
     assert_eq!(part2(&topo), 3);
 }
 
@@ -190,6 +200,13 @@ fn sample_input_6() {
         4.6789\n\
         56789.\n\
         ";
-    let topo = parse(input);
+    let topo = parse(input).unwrap();
     assert_eq!(part2(&topo), 227);
 }
+
+#[test]
+fn isolated_trailhead() {
+    let topo = parse("10\n.0\n").unwrap();
+    assert_eq!(part1(&topo), 0);
+    assert_eq!(part2(&topo), 0);
+}
@@ -1,8 +1,14 @@
+use crate::day::impl_day;
+use crate::error::Error;
 use crate::util::parse::ParseOps;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 
-pub fn parse(input: &str) -> Vec<u64> {
+pub struct Day11;
+
+impl_day!(Day11, Vec<u64>, 11, "Plutonian Pebbles");
+
+pub fn parse(input: &str) -> Result<Vec<u64>, Error> {
     input.iter_unsigned().collect()
 }
 
@@ -51,7 +57,7 @@ fn count(source_stone: u64, total_blinks: u8, cache: &mut Cache) -> u64 {
             }
         } else {
             let d = num_digits(&stone);
-            if d % 2 == 0 {
+            if d.is_multiple_of(2) {
                 let div = 10_u64.pow(d / 2);
                 let key_next1 = (stone / div, blinks + 1);
                 let key_next2 = (stone % div, blinks + 1);
@@ -93,7 +99,7 @@ fn sample_input_1() {
     let input = "\
         125 17\n\
         ";
-    let stones = parse(input);
+    let stones = parse(input).unwrap();
     assert_eq!(part1(&stones), 55312);
     assert_eq!(part2(&stones), 65_601_038_650_482);
 }
@@ -103,7 +109,7 @@ fn sample_input_2() {
     let input = "\
         0 1 10 99 999\n\
         ";
-    let stones = parse(input);
+    let stones = parse(input).unwrap();
     assert_eq!(part1(&stones), 125681);
     assert_eq!(part2(&stones), 149_161_030_616_311);
 }
@@ -113,7 +119,7 @@ fn cache_check() {
     let input = "\
         0 0 0 0 0 0 0 0 0 0\n\
         ";
-    let stones = parse(input);
+    let stones = parse(input).unwrap();
     assert_eq!(part1(&stones), 19778 * 10);
     assert_eq!(part2(&stones), 22938365706844 * 10);
 }
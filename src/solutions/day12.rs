@@ -0,0 +1,183 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::grid::Grid;
+use crate::util::point::{Direction8, Point};
+
+pub struct Day12;
+
+impl_day!(Day12, Garden, 12, "Garden Groups");
+
+pub fn parse(input: &str) -> Result<Garden, Error> {
+    let plots = Grid::parse(input)?;
+
+    for (j, line) in input.lines().enumerate() {
+        if let Some(i) = line.find(|c: char| !c.is_ascii_uppercase()) {
+            return Err(Error::new(j + 1, i + 1, "expected a plant type"));
+        }
+    }
+
+    // Flood fill each region from the first plot which doesn't belong to a region yet.
+    let mut regions = Vec::new();
+    let mut visited = plots.map(|_| false);
+    for start in plots.points() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+
+        let mut region = vec![start];
+        let mut stack = vec![start];
+        while let Some(p) = stack.pop() {
+            for n in plots.neighbours4(p) {
+                if !visited[n] && plots[n] == plots[start] {
+                    visited[n] = true;
+                    region.push(n);
+                    stack.push(n);
+                }
+            }
+        }
+        regions.push(region);
+    }
+
+    Ok(Garden { plots, regions })
+}
+
+pub fn part1(garden: &Garden) -> usize {
+    garden
+        .regions
+        .iter()
+        .map(|region| {
+            let perimeter: usize = region
+                .iter()
+                .map(|p| {
+                    // Each side which doesn't touch a plot of the same region is a fence.
+                    4 - garden
+                        .plots
+                        .neighbours4(*p)
+                        .filter(|n| garden.plots[*n] == garden.plots[*p])
+                        .count()
+                })
+                .sum();
+            region.len() * perimeter
+        })
+        .sum()
+}
+
+pub fn part2(garden: &Garden) -> usize {
+    garden
+        .regions
+        .iter()
+        .map(|region| {
+            // A polygon has as many sides as it has corners.
+            let sides: usize = region.iter().map(|p| garden.corners(*p)).sum();
+            region.len() * sides
+        })
+        .sum()
+}
+
+pub struct Garden {
+    plots: Grid<u8>,
+    regions: Vec<Vec<Point>>, // plots of each region
+}
+
+impl Garden {
+    // Number of corners of the region at the given plot.
+    fn corners(&self, p: Point) -> usize {
+        let plant = self.plots[p];
+        let same = |q: Point| self.plots.get(q) == Some(&plant);
+
+        [
+            Direction8::UpLeft,
+            Direction8::UpRight,
+            Direction8::DownRight,
+            Direction8::DownLeft,
+        ]
+        .into_iter()
+        .map(Direction8::delta)
+        .filter(|d| {
+            let horizontal = same(p + Point::new(d.x, 0));
+            let vertical = same(p + Point::new(0, d.y));
+            // outer corner, e.g. the top-left plot of a square
+            (!horizontal && !vertical)
+                // inner corner, e.g. the bend of an 'L' shape
+                || (horizontal && vertical && !same(p + *d))
+        })
+        .count()
+    }
+}
+
+#[test]
+fn sample_input_1() {
+    let input = "\
+        AAAA\n\
+        BBCD\n\
+        BBCC\n\
+        EEEC\n\
+        ";
+    let garden = parse(input).unwrap();
+    assert_eq!(part1(&garden), 140);
+    assert_eq!(part2(&garden), 80);
+
+    let garden = parse(&input.replace('\n', "\r\n")).unwrap();
+    assert_eq!(part1(&garden), 140);
+}
+
+#[test]
+fn sample_input_2() {
+    let input = "\
+        OOOOO\n\
+        OXOXO\n\
+        OOOOO\n\
+        OXOXO\n\
+        OOOOO\n\
+        ";
+    let garden = parse(input).unwrap();
+    assert_eq!(part1(&garden), 772);
+    assert_eq!(part2(&garden), 436);
+}
+
+#[test]
+fn sample_input_3() {
+    let input = "\
+        RRRRIICCFF\n\
+        RRRRIICCCF\n\
+        VVRRRCCFFF\n\
+        VVRCCCJFFF\n\
+        VVVVCJJCFE\n\
+        VVIVCCJJEE\n\
+        VVIIICJJEE\n\
+        MIIIIIJJEE\n\
+        MIIISIJEEE\n\
+        MMMISSJEEE\n\
+        ";
+    let garden = parse(input).unwrap();
+    assert_eq!(part1(&garden), 1930);
+    assert_eq!(part2(&garden), 1206);
+}
+
+#[test]
+fn sample_input_4() {
+    let input = "\
+        EEEEE\n\
+        EXXXX\n\
+        EEEEE\n\
+        EXXXX\n\
+        EEEEE\n\
+        ";
+    let garden = parse(input).unwrap();
+    assert_eq!(part2(&garden), 236);
+}
+
+#[test]
+fn sample_input_5() {
+    let input = "\
+        AAAAAA\n\
+        AAABBA\n\
+        AAABBA\n\
+        ABBAAA\n\
+        ABBAAA\n\
+        AAAAAA\n\
+        ";
+    let garden = parse(input).unwrap();
+    assert_eq!(part2(&garden), 368);
+}
@@ -0,0 +1,239 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::parse::ParseOps;
+
+pub struct Day13;
+
+impl_day!(Day13, Vec<Machine>, 13, "Claw Contraption");
+
+pub fn parse(input: &str) -> Result<Vec<Machine>, Error> {
+    let mut machines = Vec::new();
+
+    let mut line = 1;
+    for block in input.split("\n\n") {
+        // Labels such as "Button A: X+" or "Prize: X=" contain no digit, so they are skipped by
+        // the parser like any other separator.
+        let numbers: Vec<u64> = block
+            .iter_unsigned()
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.below(line - 1))?;
+        let Ok([ax, ay, bx, by, px, py]) = <[u64; 6]>::try_from(numbers) else {
+            return Err(Error::new(
+                line,
+                1,
+                "expected the offsets of buttons A and B followed by the location of the prize",
+            ));
+        };
+        machines.push(Machine {
+            a: (ax.into(), ay.into()),
+            b: (bx.into(), by.into()),
+            prize: (px.into(), py.into()),
+        });
+
+        line += block.lines().count() + 1;
+    }
+
+    Ok(machines)
+}
+
+pub fn part1(machines: &[Machine]) -> i128 {
+    machines
+        .iter()
+        .filter_map(|m| m.presses(Some(MAX_PRESSES)))
+        .map(|(a, b)| 3 * a + b)
+        .sum()
+}
+
+pub fn part2(machines: &[Machine]) -> i128 {
+    machines
+        .iter()
+        .map(|m| Machine {
+            prize: (m.prize.0 + PRIZE_OFFSET, m.prize.1 + PRIZE_OFFSET),
+            ..*m
+        })
+        .filter_map(|m| m.presses(None))
+        .map(|(a, b)| 3 * a + b)
+        .sum()
+}
+
+const MAX_PRESSES: i128 = 100;
+const PRIZE_OFFSET: i128 = 10_000_000_000_000;
+
+// Coordinates are parsed from u64 values, so all the products below fit in an i128.
+#[derive(Clone, Copy)]
+pub struct Machine {
+    a: (i128, i128), // x,y
+    b: (i128, i128),
+    prize: (i128, i128),
+}
+
+impl Machine {
+    // Cheapest number of presses of buttons A and B, at most 'max' each, which move the claw
+    // exactly to the prize, if any.
+    //
+    // The presses are the solution of the linear system
+    //   a·ax + b·bx = px
+    //   a·ay + b·by = py
+    // which, by Cramer's rule, is unique unless both buttons move the claw along the same line.
+    // In the latter case, the prize is reached along a single axis, and checked on the other.
+    fn presses(&self, max: Option<i128>) -> Option<(i128, i128)> {
+        let Machine {
+            a: (ax, ay),
+            b: (bx, by),
+            prize: (px, py),
+        } = *self;
+
+        let det = ax * by - ay * bx;
+        let (a, b) = if det != 0 {
+            let (a_num, b_num) = (px * by - py * bx, ax * py - ay * px);
+            if a_num % det != 0 || b_num % det != 0 {
+                return None;
+            }
+            (a_num / det, b_num / det)
+        } else {
+            let (ak, bk, pk) = if (ax, bx) != (0, 0) {
+                (ax, bx, px)
+            } else {
+                (ay, by, py)
+            };
+            match (ak, bk) {
+                // Neither button moves the claw.
+                (0, 0) => (0, 0),
+                // A button which doesn't move the claw is never worth pressing.
+                (_, 0) => (pk / ak, 0),
+                (0, _) => (0, pk / bk),
+                _ => cheapest(ak, bk, pk, max)?,
+            }
+        };
+
+        let within = |n: i128| n >= 0 && max.is_none_or(|max| n <= max);
+        (within(a) && within(b) && a * ax + b * bx == px && a * ay + b * by == py).then_some((a, b))
+    }
+}
+
+// Cheapest non-negative presses, at most 'max' each, such that a·ak + b·bk = pk, for non-zero
+// offsets 'ak' and 'bk'.
+//
+// All the solutions are a = a0 + t·m and b = b0 - t·n for an integer t, from a particular solution
+// found with the extended Euclidean algorithm. The cost 3a + b is linear in t, so the cheapest
+// solution is at one end of the range of t which keeps a and b within bounds.
+fn cheapest(ak: i128, bk: i128, pk: i128, max: Option<i128>) -> Option<(i128, i128)> {
+    let (g, x) = extended_gcd(ak, bk);
+    if pk % g != 0 {
+        return None;
+    }
+    let (m, n) = (bk / g, ak / g);
+    let a0 = (x % m) * ((pk / g) % m) % m;
+    let b0 = (pk - a0 * ak) / bk;
+
+    // Each bound c0 + t·c1 ≥ 0 limits t from below or from above, as c1 is never zero.
+    let mut bounds = vec![(a0, m), (b0, -n)];
+    if let Some(max) = max {
+        bounds.extend([(max - a0, -m), (max - b0, n)]);
+    }
+    let (mut lo, mut hi) = (None, None);
+    for (c0, c1) in bounds {
+        if c1 > 0 {
+            lo = lo.max(Some(-c0.div_euclid(c1)));
+        } else {
+            let bound = c0.div_euclid(-c1);
+            hi = Some(hi.map_or(bound, |hi: i128| hi.min(bound)));
+        }
+    }
+    if let (Some(lo), Some(hi)) = (lo, hi) {
+        if lo > hi {
+            return None;
+        }
+    }
+
+    // The bounds of a and b ≥ 0 always limit t on the side where the cost decreases.
+    let t = if 3 * m - n > 0 { lo } else { hi }?;
+    Some((a0 + t * m, b0 - t * n))
+}
+
+// Greatest common divisor g > 0 of a and b, along with x such that a·x ≡ g (mod b).
+fn extended_gcd(a: i128, b: i128) -> (i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+    }
+    if r0 < 0 {
+        (-r0, -x0)
+    } else {
+        (r0, x0)
+    }
+}
+
+#[test]
+fn sample_input() {
+    let input = "\
+        Button A: X+94, Y+34\n\
+        Button B: X+22, Y+67\n\
+        Prize: X=8400, Y=5400\n\
+        \n\
+        Button A: X+26, Y+66\n\
+        Button B: X+67, Y+21\n\
+        Prize: X=12748, Y=12176\n\
+        \n\
+        Button A: X+17, Y+86\n\
+        Button B: X+84, Y+37\n\
+        Prize: X=7870, Y=6450\n\
+        \n\
+        Button A: X+69, Y+23\n\
+        Button B: X+27, Y+71\n\
+        Prize: X=18641, Y=10279\n\
+        ";
+    let machines = parse(input).unwrap();
+    assert_eq!(part1(&machines), 480);
+    assert_eq!(part2(&machines), 875_318_608_908);
+}
+
+#[test]
+fn incomplete_machine() {
+    let input = "\
+        Button A: X+94, Y+34\n\
+        Button B: X+22, Y+67\n\
+        Prize: X=8400, Y=5400\n\
+        \n\
+        Button A: X+26, Y+66\n\
+        Prize: X=12748, Y=12176\n\
+        ";
+    assert_eq!(parse(input).err().map(|e| (e.line, e.column)), Some((5, 1)));
+}
+
+#[test]
+fn colinear_buttons() {
+    // B moves the claw further per token, but A is needed to reach the prize exactly.
+    let input = "\
+        Button A: X+3, Y+6\n\
+        Button B: X+2, Y+4\n\
+        Prize: X=23, Y=46\n\
+        \n\
+        Button A: X+10, Y+10\n\
+        Button B: X+1, Y+1\n\
+        Prize: X=23, Y=23\n\
+        \n\
+        Button A: X+1, Y+1\n\
+        Button B: X+2, Y+2\n\
+        Prize: X=5, Y=6\n\
+        ";
+    let machines = parse(input).unwrap();
+    let presses: Vec<_> = machines.iter().map(|m| m.presses(None)).collect();
+    assert_eq!(presses, [Some((1, 10)), Some((2, 3)), None]);
+    assert_eq!(part1(&machines), 13 + 9);
+}
+
+#[test]
+fn large_prize() {
+    let input = "\
+        Button A: X+94, Y+34\n\
+        Button B: X+22, Y+67\n\
+        Prize: X=1000000000000000000, Y=18446744073709551615\n\
+        ";
+    let machines = parse(input).unwrap();
+    assert_eq!(part1(&machines), 0);
+    assert_eq!(part2(&machines), 0);
+}
@@ -0,0 +1,156 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::parse::ParseOps;
+use std::cmp::Ordering;
+
+pub struct Day14;
+
+impl_day!(Day14, Vec<Robot>, 14, "Restroom Redoubt");
+
+pub fn parse(input: &str) -> Result<Vec<Robot>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(j, l)| {
+            let numbers: Vec<i32> = l
+                .iter_signed()
+                .collect::<Result<_, _>>()
+                .map_err(|e| e.below(j))?;
+            match numbers[..] {
+                [px, py, vx, vy, ..] => Ok(Robot {
+                    p: (px, py),
+                    v: (vx, vy),
+                }),
+                _ => Err(Error::new(
+                    j + 1,
+                    l.len() + 1,
+                    "expected a position and a velocity",
+                )),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(robots: &[Robot]) -> usize {
+    safety_factor(robots, WIDTH, HEIGHT, 100)
+}
+
+pub fn part2(robots: &[Robot]) -> i32 {
+    easter_egg(robots, WIDTH, HEIGHT)
+}
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+// Product of the number of robots in each quadrant of the arena after the given number of
+// seconds. Robots located exactly in the middle of the arena don't count.
+pub fn safety_factor(robots: &[Robot], width: i32, height: i32, seconds: i32) -> usize {
+    let (mid_x, mid_y) = (width / 2, height / 2);
+
+    let mut quadrants = [0; 4];
+    robots
+        .iter()
+        .map(|r| r.position(width, height, seconds))
+        .for_each(|(x, y)| match (x.cmp(&mid_x), y.cmp(&mid_y)) {
+            (Ordering::Less, Ordering::Less) => quadrants[0] += 1,
+            (Ordering::Greater, Ordering::Less) => quadrants[1] += 1,
+            (Ordering::Less, Ordering::Greater) => quadrants[2] += 1,
+            (Ordering::Greater, Ordering::Greater) => quadrants[3] += 1,
+            _ => (),
+        });
+    quadrants.iter().product()
+}
+
+// Earliest second at which the robots arrange themselves into a picture.
+//
+// While drawing the picture, most robots gather in a small area, so the variance of their
+// positions drops on both axes. Horizontal positions repeat every 'width' seconds and vertical
+// positions every 'height' seconds, so the lowest variance on each axis is found independently,
+// then both are combined with the Chinese remainder theorem. This requires the dimensions of the
+// arena to be coprime, which holds for the puzzle's arena.
+pub fn easter_egg(robots: &[Robot], width: i32, height: i32) -> i32 {
+    let lowest_variance = |period: i32, axis: fn((i32, i32)) -> i32| {
+        (0..period)
+            .min_by_key(|t| {
+                let coords = robots.iter().map(|r| axis(r.position(width, height, *t)));
+                variance(coords)
+            })
+            .unwrap_or_default()
+    };
+    let t_x = lowest_variance(width, |(x, _)| x);
+    let t_y = lowest_variance(height, |(_, y)| y);
+
+    // t ≡ t_x (mod width) and t ≡ t_y (mod height)
+    (0..height)
+        .map(|k| t_x + k * width)
+        .find(|t| t % height == t_y)
+        .unwrap_or(t_x)
+}
+
+// Variance scaled by n², which preserves its ordering while keeping integer arithmetic.
+fn variance(values: impl Iterator<Item = i32>) -> i64 {
+    let (n, sum, sum_sq) = values.fold((0, 0, 0), |(n, sum, sum_sq), v| {
+        let v = v as i64;
+        (n + 1, sum + v, sum_sq + v * v)
+    });
+    n * sum_sq - sum * sum
+}
+
+pub struct Robot {
+    p: (i32, i32), // x,y
+    v: (i32, i32),
+}
+
+impl Robot {
+    // Computed in i64, as i32 velocities multiplied by the number of seconds may overflow.
+    fn position(&self, width: i32, height: i32, seconds: i32) -> (i32, i32) {
+        let wrap = |p: i32, v: i32, size: i32| {
+            (p as i64 + v as i64 * seconds as i64).rem_euclid(size as i64) as i32
+        };
+        (
+            wrap(self.p.0, self.v.0, width),
+            wrap(self.p.1, self.v.1, height),
+        )
+    }
+}
+
+#[test]
+fn sample_input() {
+    let input = "\
+        p=0,4 v=3,-3\n\
+        p=6,3 v=-1,-3\n\
+        p=10,3 v=-1,2\n\
+        p=2,0 v=2,-1\n\
+        p=0,0 v=1,3\n\
+        p=3,0 v=-2,-2\n\
+        p=7,6 v=-1,-3\n\
+        p=3,0 v=-1,-2\n\
+        p=9,3 v=2,3\n\
+        p=7,3 v=-1,2\n\
+        p=2,4 v=2,-3\n\
+        p=9,5 v=-3,-3\n\
+        ";
+    let robots = parse(input).unwrap();
+    assert_eq!(safety_factor(&robots, 11, 7, 100), 12);
+}
+
+#[test]
+fn gathering_robots() {
+    // All robots meet at (3,2) after 40 seconds, then scatter again.
+    let input = "\
+        p=10,6 v=-1,2\n\
+        p=0,3 v=2,-3\n\
+        p=2,4 v=-3,1\n\
+        p=4,0 v=3,-1\n\
+        ";
+    let robots = parse(input).unwrap();
+    assert!(robots.iter().all(|r| r.position(11, 7, 40) == (3, 2)));
+    assert_eq!(easter_egg(&robots, 11, 7), 40);
+}
+
+#[test]
+fn fast_robot() {
+    let robots = parse("p=0,4 v=300000000,-3").unwrap();
+    assert_eq!(robots[0].position(11, 7, 100), (3, 5));
+    assert_eq!(safety_factor(&robots, 11, 7, 100), 0);
+}
@@ -0,0 +1,310 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::grid::Grid;
+use crate::util::point::{Direction, Point};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{self, Display, Formatter};
+
+pub struct Day15;
+
+impl_day!(Day15, (Warehouse, Vec<Direction>), 15, "Warehouse Woes");
+
+pub fn parse(input: &str) -> Result<(Warehouse, Vec<Direction>), Error> {
+    let (map, moves) = input.split_once("\n\n").unwrap_or((input, ""));
+    let grid = Grid::parse(map)?;
+
+    let mut robot = None;
+    for (j, line) in map.lines().enumerate() {
+        for (i, c) in line.chars().enumerate() {
+            // The robot moves without bounds checks, so it must be enclosed by walls.
+            let border = j == 0 || j == grid.height - 1 || i == 0 || i == grid.width - 1;
+            if border && c != '#' {
+                return Err(Error::new(
+                    j + 1,
+                    i + 1,
+                    "expected a wall '#' around the map",
+                ));
+            }
+            match c {
+                '#' | '.' | 'O' => (),
+                '@' if robot.is_some() => {
+                    return Err(Error::new(j + 1, i + 1, "duplicate robot position '@'"))
+                }
+                '@' => robot = Some(Point::new(i as i32, j as i32)),
+                _ => {
+                    return Err(Error::new(
+                        j + 1,
+                        i + 1,
+                        "expected one of '#', '.', 'O', '@'",
+                    ))
+                }
+            }
+        }
+    }
+    let Some(robot) = robot else {
+        return Err(Error::new(1, 1, "missing robot position '@'"));
+    };
+
+    let mut parsed_moves = Vec::new();
+    for (j, line) in moves.lines().enumerate() {
+        for (i, c) in line.chars().enumerate() {
+            parsed_moves.push(match c {
+                '^' => Direction::Up,
+                '>' => Direction::Right,
+                'v' => Direction::Down,
+                '<' => Direction::Left,
+                _ => {
+                    return Err(Error::new(
+                        grid.height + j + 2,
+                        i + 1,
+                        "expected one of '^', '>', 'v', '<'",
+                    ))
+                }
+            });
+        }
+    }
+
+    let tiles = grid.map(|c| match c {
+        b'#' => Tile::Wall,
+        b'O' => Tile::Box,
+        _ => Tile::Empty,
+    });
+    Ok((Warehouse { tiles, robot }, parsed_moves))
+}
+
+pub fn part1(r#in: &(Warehouse, Vec<Direction>)) -> usize {
+    let (warehouse, moves) = r#in;
+    simulate(warehouse, moves).gps_sum()
+}
+
+pub fn part2(r#in: &(Warehouse, Vec<Direction>)) -> usize {
+    let (warehouse, moves) = r#in;
+    simulate(&warehouse.widen(), moves).gps_sum()
+}
+
+// Returns the state of the warehouse after the robot attempted all the given moves.
+pub fn simulate(warehouse: &Warehouse, moves: &[Direction]) -> Warehouse {
+    let mut warehouse = warehouse.clone();
+    for m in moves {
+        warehouse.step(*m);
+    }
+    warehouse
+}
+
+#[derive(Clone)]
+pub struct Warehouse {
+    tiles: Grid<Tile>,
+    robot: Point,
+}
+
+impl Warehouse {
+    // Doubles the width of every tile. Boxes become two tiles wide, the robot doesn't.
+    pub fn widen(&self) -> Self {
+        let mut tiles = Grid::new(self.tiles.width * 2, self.tiles.height, Tile::Empty);
+        for p in self.tiles.points() {
+            let halves = match self.tiles[p] {
+                Tile::Box => [Tile::BoxLeft, Tile::BoxRight],
+                t => [t, t],
+            };
+            tiles.set(Point::new(p.x * 2, p.y), halves[0]);
+            tiles.set(Point::new(p.x * 2 + 1, p.y), halves[1]);
+        }
+
+        Self {
+            tiles,
+            robot: Point::new(self.robot.x * 2, self.robot.y),
+        }
+    }
+
+    fn step(&mut self, m: Direction) {
+        let next = |p: Point| p + m.delta();
+
+        // Positions of the robot and of all box tiles it pushes, in the order they were reached.
+        // The map is surrounded by walls, as checked by parse(), so positions never go out of
+        // bounds.
+        let mut pushed = vec![self.robot];
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::from([self.robot]);
+        while let Some(p) = queue.pop_front() {
+            let nxt = next(p);
+            let tiles = match self.tiles[nxt] {
+                Tile::Wall => return,
+                Tile::Empty => continue,
+                Tile::Box => [Some(nxt), None],
+                // Both halves of a wide box move together.
+                Tile::BoxLeft => [Some(nxt), Some(nxt + Direction::Right.delta())],
+                Tile::BoxRight => [Some(nxt), Some(nxt + Direction::Left.delta())],
+            };
+            for t in tiles.into_iter().flatten() {
+                if seen.insert(t) {
+                    pushed.push(t);
+                    queue.push_back(t);
+                }
+            }
+        }
+
+        // Tiles which were reached last are the furthest from the robot, so they are moved
+        // first to free the way for the others.
+        for p in pushed.into_iter().rev() {
+            self.tiles[next(p)] = self.tiles[p];
+            self.tiles[p] = Tile::Empty;
+        }
+        self.robot = next(self.robot);
+    }
+
+    fn gps_sum(&self) -> usize {
+        self.tiles
+            .points()
+            .filter(|p| matches!(self.tiles[*p], Tile::Box | Tile::BoxLeft))
+            .map(|p| 100 * p.y as usize + p.x as usize)
+            .sum()
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (j, row) in self.tiles.rows().enumerate() {
+            for (i, t) in row.iter().enumerate() {
+                let c = match t {
+                    _ if Point::new(i as i32, j as i32) == self.robot => '@',
+                    Tile::Empty => '.',
+                    Tile::Wall => '#',
+                    Tile::Box => 'O',
+                    Tile::BoxLeft => '[',
+                    Tile::BoxRight => ']',
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Tile {
+    Empty,
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+}
+
+#[test]
+fn sample_input_1() {
+    let input = "\
+        ########\n\
+        #..O.O.#\n\
+        ##@.O..#\n\
+        #...O..#\n\
+        #.#.O..#\n\
+        #...O..#\n\
+        #......#\n\
+        ########\n\
+        \n\
+        <^^>>>vv<v>>v<<\n\
+        ";
+    let out = parse(input).unwrap();
+    assert_eq!(part1(&out), 2028);
+
+    let (warehouse, moves) = out;
+    assert_eq!(
+        simulate(&warehouse, &moves).to_string(),
+        "\
+        ########\n\
+        #....OO#\n\
+        ##.....#\n\
+        #.....O#\n\
+        #.#O@..#\n\
+        #...O..#\n\
+        #...O..#\n\
+        ########\n\
+        "
+    );
+}
+
+#[test]
+fn sample_input_2() {
+    let input = "\
+        ##########\n\
+        #..O..O.O#\n\
+        #......O.#\n\
+        #.OO..O.O#\n\
+        #..O@..O.#\n\
+        #O#..O...#\n\
+        #O..O..O.#\n\
+        #.OO.O.OO#\n\
+        #....O...#\n\
+        ##########\n\
+        \n\
+        <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^\n\
+        vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v\n\
+        ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<\n\
+        <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^\n\
+        ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><\n\
+        ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^\n\
+        >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^\n\
+        <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>\n\
+        ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>\n\
+        v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^\n\
+        ";
+    let out = parse(input).unwrap();
+    assert_eq!(part1(&out), 10092);
+    assert_eq!(part2(&out), 9021);
+}
+
+#[test]
+fn sample_input_3() {
+    let input = "\
+        #######\n\
+        #...#.#\n\
+        #.....#\n\
+        #..OO@#\n\
+        #..O..#\n\
+        #.....#\n\
+        #######\n\
+        \n\
+        <vv<<^^<<^^\n\
+        ";
+    let (warehouse, moves) = parse(input).unwrap();
+    assert_eq!(
+        simulate(&warehouse.widen(), &moves).to_string(),
+        "\
+        ##############\n\
+        ##...[].##..##\n\
+        ##...@.[]...##\n\
+        ##....[]....##\n\
+        ##..........##\n\
+        ##..........##\n\
+        ##############\n\
+        "
+    );
+}
+
+#[test]
+fn invalid_map() {
+    assert_eq!(
+        parse("@O\n\n>\n").err(),
+        Some(Error::new(1, 1, "expected a wall '#' around the map"))
+    );
+
+    let input = "\
+        #####\n\
+        #@.@#\n\
+        #####\n\
+        \n\
+        >\n\
+        ";
+    assert_eq!(
+        parse(input).err(),
+        Some(Error::new(2, 4, "duplicate robot position '@'"))
+    );
+}
+
+#[test]
+fn ragged_map() {
+    assert_eq!(
+        parse("####\n#@#\n####\n\n>\n").err(),
+        Some(Error::new(2, 4, "expected 4 columns, found 3"))
+    );
+}
@@ -0,0 +1,172 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::grid::Grid;
+use crate::util::point::{Direction, Point};
+use petgraph::algo::{dijkstra, has_path_connecting};
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeRef, Reversed};
+use petgraph::{Directed, Graph};
+use std::collections::HashSet;
+
+pub struct Day16;
+
+impl_day!(Day16, Maze, 16, "Reindeer Maze");
+
+pub fn parse(input: &str) -> Result<Maze, Error> {
+    let tiles = Grid::parse(input)?;
+
+    let (mut start, mut end) = (None, None);
+    for (j, line) in input.lines().enumerate() {
+        for (i, c) in line.chars().enumerate() {
+            match c {
+                '#' | '.' => (),
+                'S' if start.is_none() => start = Some(Point::new(i as i32, j as i32)),
+                'E' if end.is_none() => end = Some(Point::new(i as i32, j as i32)),
+                _ => {
+                    return Err(Error::new(
+                        j + 1,
+                        i + 1,
+                        "expected one of '#', '.', 'S', 'E'",
+                    ))
+                }
+            }
+        }
+    }
+    let (Some(start), Some(end)) = (start, end) else {
+        return Err(Error::new(1, 1, "missing start tile 'S' or end tile 'E'"));
+    };
+
+    // Each state of the reindeer is a node, identified by its tile and its facing. All states on
+    // the end tile lead to an extra node, so that a single node stands for the end of the maze.
+    let sink = tiles.width * tiles.height * 4;
+    // Points of the grid are always within bounds.
+    let state = |p: Point, facing: Direction| tiles.offset(p).unwrap() * 4 + facing as usize;
+
+    let mut edges = Vec::new();
+    for p in tiles.points().filter(|p| tiles[*p] != b'#') {
+        for facing in Direction::ALL {
+            let n = p + facing.delta();
+            if tiles.get(n).is_some_and(|tile| *tile != b'#') {
+                edges.push((state(p, facing), state(n, facing), MOVE_COST));
+            }
+            edges.push((state(p, facing), state(p, facing.turn_right()), TURN_COST));
+            edges.push((state(p, facing), state(p, facing.turn_left()), TURN_COST));
+            if p == end {
+                edges.push((state(p, facing), sink, 0));
+            }
+        }
+    }
+
+    let maze = Maze {
+        graph: Graph::from_edges(edges),
+        start: state(start, Direction::Right).into(),
+        end: sink.into(),
+    };
+    // Both parts rely on the end being reachable, so that it has a best score.
+    if !has_path_connecting(&maze.graph, maze.start, maze.end, None) {
+        return Err(Error::new(
+            end.y as usize + 1,
+            end.x as usize + 1,
+            "end tile 'E' is unreachable from the start tile 'S'",
+        ));
+    }
+    Ok(maze)
+}
+
+pub fn part1(maze: &Maze) -> u32 {
+    let scores = dijkstra(&maze.graph, maze.start, Some(maze.end), |e| *e.weight());
+    scores[&maze.end]
+}
+
+pub fn part2(maze: &Maze) -> usize {
+    let from_start = dijkstra(&maze.graph, maze.start, None, |e| *e.weight());
+    let to_end = dijkstra(Reversed(&maze.graph), maze.end, None, |e| *e.weight());
+    let best = from_start[&maze.end];
+
+    // A state lies on an optimal path if the best paths leading to it and away from it add up to
+    // the lowest score.
+    from_start
+        .iter()
+        .filter(|(node, score)| {
+            *node != &maze.end && to_end.get(node).is_some_and(|t| *score + t == best)
+        })
+        .map(|(node, _)| node.index() / 4) // offset of the tile
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+const MOVE_COST: u32 = 1;
+const TURN_COST: u32 = 1000;
+
+pub struct Maze {
+    graph: Graph<(), u32, Directed, usize>,
+    start: NodeIndex<usize>,
+    end: NodeIndex<usize>,
+}
+
+#[test]
+fn sample_input_1() {
+    let input = "\
+        ###############\n\
+        #.......#....E#\n\
+        #.#.###.#.###.#\n\
+        #.....#.#...#.#\n\
+        #.###.#####.#.#\n\
+        #.#.#.......#.#\n\
+        #.#.#####.###.#\n\
+        #...........#.#\n\
+        ###.#.#####.#.#\n\
+        #...#.....#.#.#\n\
+        #.#.#.###.#.#.#\n\
+        #.....#...#.#.#\n\
+        #.###.#.#.#.#.#\n\
+        #S..#.....#...#\n\
+        ###############\n\
+        ";
+    let maze = parse(input).unwrap();
+    assert_eq!(part1(&maze), 7036);
+    assert_eq!(part2(&maze), 45);
+}
+
+#[test]
+fn sample_input_2() {
+    let input = "\
+        #################\n\
+        #...#...#...#..E#\n\
+        #.#.#.#.#.#.#.#.#\n\
+        #.#.#.#...#...#.#\n\
+        #.#.#.#.###.#.#.#\n\
+        #...#.#.#.....#.#\n\
+        #.#.#.#.#.#####.#\n\
+        #.#...#.#.#.....#\n\
+        #.#.#####.#.###.#\n\
+        #.#.#.......#...#\n\
+        #.#.###.#####.###\n\
+        #.#.#...#.....#.#\n\
+        #.#.#.#####.###.#\n\
+        #.#.#.........#.#\n\
+        #.#.#.#########.#\n\
+        #S#.............#\n\
+        #################\n\
+        ";
+    let maze = parse(input).unwrap();
+    assert_eq!(part1(&maze), 11048);
+    assert_eq!(part2(&maze), 64);
+}
+
+#[test]
+fn unreachable_end() {
+    let input = "\
+        S#E\n\
+        ###\n\
+        ...\n\
+        ";
+    assert_eq!(
+        parse(input).err(),
+        Some(Error::new(
+            1,
+            3,
+            "end tile 'E' is unreachable from the start tile 'S'"
+        ))
+    );
+}
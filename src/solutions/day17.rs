@@ -0,0 +1,373 @@
+use crate::day::{Answer, Day};
+use crate::error::Error;
+use crate::util::parse::ParseOps;
+use std::fmt::{self, Display, Formatter};
+
+pub struct Day17;
+
+impl Day for Day17 {
+    type Input = Computer;
+
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Chronospatial Computer";
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        Answer::new(part1(input), "the program doesn't halt")
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        Answer::new(part2(input), "no value of A outputs the program")
+    }
+}
+
+pub fn parse(input: &str) -> Result<Computer, Error> {
+    let mut lines = input.lines();
+
+    let mut registers = [0; 3];
+    for (j, register) in registers.iter_mut().enumerate() {
+        *register = lines
+            .next()
+            .and_then(|l| l.unsigned())
+            .ok_or_else(|| Error::new(j + 1, 1, "expected the value of a register"))?
+            .map_err(|e| e.below(j))?;
+    }
+
+    // The program follows an empty line.
+    let line = lines.nth(1).unwrap_or_default();
+    let Some((label, values)) = line.split_once(": ") else {
+        return Err(Error::new(5, 1, "expected a program"));
+    };
+
+    let mut program = Vec::new();
+    let mut column = label.len() + 3;
+    for value in values.split(',') {
+        match value.as_bytes() {
+            [digit @ b'0'..=b'7'] => program.push(digit - b'0'),
+            _ => return Err(Error::new(5, column, "expected a 3-bit number")),
+        }
+        column += value.len() + 1;
+    }
+
+    // All values are single digits, so the operand of the n-th instruction is at column
+    // label + 3 + 2·(2n+1).
+    for (n, pair) in program.chunks_exact(2).enumerate() {
+        if Instruction::new(pair[0], pair[1]).combo() == Some(Operand::Reserved) {
+            let column = label.len() + 3 + 2 * (2 * n + 1);
+            return Err(Error::new(5, column, "combo operand 7 is reserved"));
+        }
+    }
+
+    Ok(Computer {
+        registers,
+        ip: 0,
+        program,
+    })
+}
+
+pub fn part1(computer: &Computer) -> Option<String> {
+    let output = computer.clone().run()?;
+    Some(
+        output
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+pub fn part2(computer: &Computer) -> Option<u64> {
+    quine(computer)
+}
+
+// Lowest initial value of register A for which the program outputs a copy of itself, if any.
+//
+// The programs from the puzzle's inputs loop until A is zero, and each iteration outputs a value
+// which depends on A, then shifts A right by 3 bits. The last value output only depends on the
+// highest 3 bits of A, the value before on the highest 6 bits, and so on, so A can be searched 3
+// bits at a time, starting from the end of the program. Trying the lowest bits first ensures the
+// first value found is the lowest.
+pub fn quine(computer: &Computer) -> Option<u64> {
+    fn search(computer: &Computer, a: u64, suffix: usize) -> Option<u64> {
+        if suffix == 0 {
+            return Some(a);
+        }
+        (0..8)
+            .map(|bits| a << 3 | bits)
+            .filter(|a| computer.run_with(*a).as_deref() == Some(&computer.program[suffix - 1..]))
+            .find_map(|a| search(computer, a, suffix - 1))
+    }
+
+    search(computer, 0, computer.program.len())
+}
+
+/// Number of instructions after which a program is assumed to loop forever. The programs from the
+/// puzzle's inputs halt after a few hundred.
+pub const STEP_LIMIT: usize = 100_000;
+
+#[derive(Clone)]
+pub struct Computer {
+    pub registers: [u64; 3], // A, B, C
+    pub ip: usize,
+    program: Vec<u8>,
+}
+
+impl Computer {
+    /// Runs the program until it halts, and returns its output. Returns `None` if the program is
+    /// still running after [`STEP_LIMIT`] instructions, as it may loop forever.
+    pub fn run(&mut self) -> Option<Vec<u8>> {
+        let mut output = Vec::new();
+        for _ in 0..STEP_LIMIT {
+            let Some(trace) = self.step() else {
+                return Some(output);
+            };
+            output.extend(trace.output);
+        }
+        None
+    }
+
+    // Runs the program from the start, with the given value in register A.
+    fn run_with(&self, a: u64) -> Option<Vec<u8>> {
+        let mut computer = self.clone();
+        computer.registers[0] = a;
+        computer.ip = 0;
+        computer.run()
+    }
+
+    /// Executes the instruction at the instruction pointer, unless the program halted.
+    pub fn step(&mut self) -> Option<Trace> {
+        let ip = self.ip;
+        let instruction = Instruction::new(*self.program.get(ip)?, *self.program.get(ip + 1)?);
+        let combo = instruction.combo().map(|c| self.value(c));
+        let [a, b, c] = &mut self.registers;
+
+        let mut output = None;
+        self.ip += 2;
+        match (instruction.opcode, combo) {
+            (Opcode::Adv, Some(v)) => *a = shr(*a, v),
+            (Opcode::Bxl, _) => *b ^= instruction.operand as u64,
+            (Opcode::Bst, Some(v)) => *b = v % 8,
+            (Opcode::Jnz, _) if *a != 0 => self.ip = instruction.operand as usize,
+            (Opcode::Jnz, _) => (),
+            (Opcode::Bxc, _) => *b ^= *c,
+            (Opcode::Out, Some(v)) => output = Some((v % 8) as u8),
+            (Opcode::Bdv, Some(v)) => *b = shr(*a, v),
+            (Opcode::Cdv, Some(v)) => *c = shr(*a, v),
+            // Instructions with a combo operand always have its value.
+            _ => unreachable!(),
+        }
+
+        Some(Trace {
+            ip,
+            instruction,
+            registers: self.registers,
+            output,
+        })
+    }
+
+    /// Lists the instructions of the program, one per line, prefixed by their address.
+    pub fn disassemble(&self) -> String {
+        self.program
+            .chunks_exact(2)
+            .enumerate()
+            .map(|(n, pair)| format!("{}: {}\n", 2 * n, Instruction::new(pair[0], pair[1])))
+            .collect()
+    }
+
+    fn value(&self, operand: Operand) -> u64 {
+        match operand {
+            Operand::Literal(v) => v as u64,
+            Operand::Register(r) => self.registers[r],
+            // Rejected while parsing, but a jump to an odd address could still read one.
+            Operand::Reserved => 0,
+        }
+    }
+}
+
+// Shifts right, saturating to zero rather than overflowing.
+fn shr(value: u64, shift: u64) -> u64 {
+    value
+        .checked_shr(shift.try_into().unwrap_or(u32::MAX))
+        .unwrap_or(0)
+}
+
+/// Effect of a single instruction.
+pub struct Trace {
+    /// Address of the instruction.
+    pub ip: usize,
+    pub instruction: Instruction,
+    /// Registers A, B and C after the instruction.
+    pub registers: [u64; 3],
+    pub output: Option<u8>,
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let [a, b, c] = self.registers;
+        let instruction = self.instruction.to_string();
+        write!(f, "{}: {instruction:<6} A={a} B={b} C={c}", self.ip)?;
+        if let Some(output) = self.output {
+            write!(f, " out={output}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instruction {
+    fn new(opcode: u8, operand: u8) -> Self {
+        let opcode = match opcode {
+            0 => Opcode::Adv,
+            1 => Opcode::Bxl,
+            2 => Opcode::Bst,
+            3 => Opcode::Jnz,
+            4 => Opcode::Bxc,
+            5 => Opcode::Out,
+            6 => Opcode::Bdv,
+            _ => Opcode::Cdv,
+        };
+        Self { opcode, operand }
+    }
+
+    // Operand of the instructions which interpret it as a combo operand.
+    fn combo(&self) -> Option<Operand> {
+        match self.opcode {
+            Opcode::Bxl | Opcode::Jnz | Opcode::Bxc => None,
+            _ => Some(match self.operand {
+                0..=3 => Operand::Literal(self.operand),
+                4..=6 => Operand::Register(self.operand as usize - 4),
+                _ => Operand::Reserved,
+            }),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mnemonic = format!("{:?}", self.opcode).to_lowercase();
+        match self.combo() {
+            None if self.opcode == Opcode::Bxc => write!(f, "{mnemonic}"),
+            None | Some(Operand::Literal(_)) => write!(f, "{mnemonic} {}", self.operand),
+            Some(Operand::Register(r)) => write!(f, "{mnemonic} {}", ['A', 'B', 'C'][r]),
+            Some(Operand::Reserved) => write!(f, "{mnemonic} ?"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operand {
+    Literal(u8),
+    Register(usize),
+    Reserved,
+}
+
+#[test]
+fn sample_input_1() {
+    let input = "\
+        Register A: 729\n\
+        Register B: 0\n\
+        Register C: 0\n\
+        \n\
+        Program: 0,1,5,4,3,0\n\
+        ";
+    let computer = parse(input).unwrap();
+    assert_eq!(part1(&computer).as_deref(), Some("4,6,3,5,6,3,5,2,1,0"));
+    assert_eq!(computer.disassemble(), "0: adv 1\n2: out A\n4: jnz 0\n");
+}
+
+#[test]
+fn sample_input_2() {
+    let input = "\
+        Register A: 2024\n\
+        Register B: 0\n\
+        Register C: 0\n\
+        \n\
+        Program: 0,3,5,4,3,0\n\
+        ";
+    let computer = parse(input).unwrap();
+    assert_eq!(part2(&computer), Some(117440));
+    assert_eq!(computer.run_with(117440), Some(computer.program.clone()));
+}
+
+#[test]
+fn small_programs() {
+    let run = |registers, program: &[u8]| {
+        let mut computer = Computer {
+            registers,
+            ip: 0,
+            program: program.to_vec(),
+        };
+        let output = computer.run().unwrap();
+        (computer.registers, output)
+    };
+
+    assert_eq!(run([0, 0, 9], &[2, 6]).0[1], 1);
+    assert_eq!(run([10, 0, 0], &[5, 0, 5, 1, 5, 4]).1, [0, 1, 2]);
+    assert_eq!(
+        run([2024, 0, 0], &[0, 1, 5, 4, 3, 0]),
+        ([0, 0, 0], vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0])
+    );
+    assert_eq!(run([0, 29, 0], &[1, 7]).0[1], 26);
+    assert_eq!(run([0, 2024, 43690], &[4, 0]).0[1], 44354);
+}
+
+#[test]
+fn tracing() {
+    let input = "\
+        Register A: 10\n\
+        Register B: 0\n\
+        Register C: 0\n\
+        \n\
+        Program: 5,0,5,1,5,4\n\
+        ";
+    let mut computer = parse(input).unwrap();
+    let trace: Vec<String> = std::iter::from_fn(|| computer.step())
+        .map(|t| t.to_string())
+        .collect();
+    assert_eq!(
+        trace,
+        [
+            "0: out 0  A=10 B=0 C=0 out=0",
+            "2: out 1  A=10 B=0 C=0 out=1",
+            "4: out A  A=10 B=0 C=0 out=2",
+        ]
+    );
+}
+
+#[test]
+fn endless_loop() {
+    let input = "\
+        Register A: 1\n\
+        Register B: 0\n\
+        Register C: 0\n\
+        \n\
+        Program: 3,0\n\
+        ";
+    let computer = parse(input).unwrap();
+    assert_eq!(part1(&computer), None);
+    assert_eq!(part2(&computer), None);
+    assert_eq!(
+        Day17::part2(&computer).to_string(),
+        "no value of A outputs the program"
+    );
+}
@@ -0,0 +1,157 @@
+use crate::day::{Answer, Day};
+use crate::error::Error;
+use crate::util::grid::Grid;
+use crate::util::parse::ParseOps;
+use crate::util::point::Point;
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+pub struct Day18;
+
+impl Day for Day18 {
+    type Input = Vec<Point>;
+
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "RAM Run";
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        Answer::new(part1(input), "no path to the exit")
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        Answer::new(part2(input), "no byte cuts off the exit")
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Point>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(j, l)| {
+            let numbers: Vec<i32> = l
+                .iter_signed()
+                .collect::<Result<_, _>>()
+                .map_err(|e| e.below(j))?;
+            match numbers[..] {
+                [x, y] => Ok(Point::new(x, y)),
+                _ => Err(Error::new(j + 1, 1, "expected the coordinates of a byte")),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(bytes: &[Point]) -> Option<usize> {
+    shortest_path(bytes, SIZE, FALLEN)
+}
+
+pub fn part2(bytes: &[Point]) -> Option<String> {
+    first_blocking(bytes, SIZE).map(|Point { x, y }| format!("{x},{y}"))
+}
+
+const SIZE: usize = 71;
+const FALLEN: usize = 1024;
+
+// Minimum number of steps from the top-left corner of the memory space to its bottom-right
+// corner, once the given number of bytes have fallen. Bytes outside the memory space are ignored.
+pub fn shortest_path(bytes: &[Point], size: usize, fallen: usize) -> Option<usize> {
+    let mut corrupted = Grid::new(size, size, false);
+    for p in bytes.iter().take(fallen) {
+        if let Some(c) = corrupted.get_mut(*p) {
+            *c = true;
+        }
+    }
+
+    let (start, exit) = (
+        Point::new(0, 0),
+        Point::new(size as i32 - 1, size as i32 - 1),
+    );
+    // A byte may fall on the start itself, leaving no way out.
+    if corrupted.get(start) != Some(&false) {
+        return None;
+    }
+    let mut steps = corrupted.map(|_| None);
+    steps[start] = Some(0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(p) = queue.pop_front() {
+        let n = steps[p]?;
+        if p == exit {
+            return Some(n);
+        }
+        for nxt in corrupted.neighbours4(p) {
+            if !corrupted[nxt] && steps[nxt].is_none() {
+                steps[nxt] = Some(n + 1);
+                queue.push_back(nxt);
+            }
+        }
+    }
+    None
+}
+
+// Coordinates of the first byte which cuts off the exit, if any.
+//
+// Once the exit is unreachable, more bytes can't open a path again, so the number of fallen bytes
+// at which this happens is found with a binary search.
+pub fn first_blocking(bytes: &[Point], size: usize) -> Option<Point> {
+    // The exit is reachable after 'reachable' bytes, and unreachable after 'blocked' bytes.
+    let (mut reachable, mut blocked) = (0, bytes.len());
+    if shortest_path(bytes, size, blocked).is_some() {
+        return None;
+    }
+    while blocked - reachable > 1 {
+        let mid = (reachable + blocked) / 2;
+        if shortest_path(bytes, size, mid).is_some() {
+            reachable = mid;
+        } else {
+            blocked = mid;
+        }
+    }
+    Some(bytes[blocked - 1])
+}
+
+#[test]
+fn sample_input() {
+    let input = "\
+        5,4\n\
+        4,2\n\
+        4,5\n\
+        3,0\n\
+        2,1\n\
+        6,3\n\
+        2,4\n\
+        1,5\n\
+        0,6\n\
+        3,3\n\
+        2,6\n\
+        5,1\n\
+        1,2\n\
+        5,5\n\
+        2,5\n\
+        6,5\n\
+        1,4\n\
+        0,4\n\
+        6,4\n\
+        1,1\n\
+        6,1\n\
+        1,0\n\
+        0,5\n\
+        1,6\n\
+        2,0\n\
+        ";
+    let bytes = parse(input).unwrap();
+    assert_eq!(shortest_path(&bytes, 7, 12), Some(22));
+    assert_eq!(first_blocking(&bytes, 7), Some(Point::new(6, 1)));
+    assert_eq!(shortest_path(&bytes, 7, bytes.len()), None);
+    assert_eq!(first_blocking(&bytes[..20], 7), None);
+}
+
+#[test]
+fn corrupted_start() {
+    let bytes = parse("1,1\n0,0").unwrap();
+    assert_eq!(shortest_path(&bytes, 3, 1), Some(4));
+    assert_eq!(shortest_path(&bytes, 3, 2), None);
+    assert_eq!(first_blocking(&bytes, 3), Some(Point::new(0, 0)));
+}
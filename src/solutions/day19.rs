@@ -0,0 +1,148 @@
+use crate::day::impl_day;
+use crate::error::Error;
+
+pub struct Day19;
+
+impl_day!(Day19, Towels, 19, "Linen Layout");
+
+pub fn parse(input: &str) -> Result<Towels, Error> {
+    let (patterns, designs) = input.split_once("\n\n").unwrap_or((input, ""));
+
+    let mut trie = Trie::default();
+    let mut column = 1;
+    for pattern in patterns.trim_end().split(", ") {
+        let colours = stripes(pattern).map_err(|i| Error::new(1, column + i, EXPECTED_COLOUR))?;
+        trie.insert(&colours);
+        column += pattern.len() + 2;
+    }
+
+    let designs = designs
+        .lines()
+        .enumerate()
+        // The designs start after the patterns and an empty line.
+        .map(|(j, design)| stripes(design).map_err(|i| Error::new(j + 3, i + 1, EXPECTED_COLOUR)))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Towels { trie, designs })
+}
+
+pub fn part1(towels: &Towels) -> usize {
+    arrangements(towels).filter(|n| *n > 0).count()
+}
+
+pub fn part2(towels: &Towels) -> u64 {
+    arrangements(towels).sum()
+}
+
+// Number of arrangements of each design.
+pub fn arrangements(towels: &Towels) -> impl Iterator<Item = u64> + '_ {
+    towels
+        .designs
+        .iter()
+        .map(|design| towels.trie.arrangements(design))
+}
+
+const EXPECTED_COLOUR: &str = "expected one of 'w', 'u', 'b', 'r', 'g'";
+
+// Colour index of each stripe, or the offset of the first character which isn't a colour.
+fn stripes(s: &str) -> Result<Vec<usize>, usize> {
+    s.bytes()
+        .enumerate()
+        .map(|(i, b)| match b {
+            b'w' => Ok(0),
+            b'u' => Ok(1),
+            b'b' => Ok(2),
+            b'r' => Ok(3),
+            b'g' => Ok(4),
+            _ => Err(i),
+        })
+        .collect()
+}
+
+pub struct Towels {
+    trie: Trie,
+    designs: Vec<Vec<usize>>, // colour of each stripe
+}
+
+// Prefix tree of the towel patterns. The root is the first node.
+struct Trie {
+    nodes: Vec<Node>,
+}
+
+#[derive(Default)]
+struct Node {
+    children: [Option<usize>; 5], // indexed by colour
+    pattern: bool,                // whether a pattern ends at this node
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+        }
+    }
+}
+
+impl Trie {
+    fn insert(&mut self, colours: &[usize]) {
+        let mut node = 0;
+        for colour in colours {
+            node = match self.nodes[node].children[*colour] {
+                Some(child) => child,
+                None => {
+                    self.nodes.push(Node::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children[*colour] = Some(child);
+                    child
+                }
+            };
+        }
+        self.nodes[node].pattern = true;
+    }
+
+    // Number of ways to split the design into patterns.
+    //
+    // Starting from the end of the design, ways[i] is the number of arrangements of the stripes
+    // from i onwards. Following the trie from i finds every pattern which starts there, and each
+    // pattern ending at j contributes the arrangements of the stripes after j.
+    fn arrangements(&self, design: &[usize]) -> u64 {
+        let mut ways = vec![0; design.len() + 1];
+        ways[design.len()] = 1;
+        for i in (0..design.len()).rev() {
+            let mut node = 0;
+            for (j, colour) in design.iter().enumerate().skip(i) {
+                let Some(child) = self.nodes[node].children[*colour] else {
+                    break;
+                };
+                node = child;
+                if self.nodes[node].pattern {
+                    ways[i] += ways[j + 1];
+                }
+            }
+        }
+        ways[0]
+    }
+}
+
+#[test]
+fn sample_input() {
+    let input = "\
+        r, wr, b, g, bwu, rb, gb, br\n\
+        \n\
+        brwrr\n\
+        bggr\n\
+        gbbr\n\
+        rrbgbr\n\
+        ubwu\n\
+        bwurrg\n\
+        brgr\n\
+        bbrgwb\n\
+        ";
+    let towels = parse(input).unwrap();
+    assert_eq!(
+        arrangements(&towels).collect::<Vec<_>>(),
+        [2, 1, 4, 6, 0, 1, 2, 0]
+    );
+    assert_eq!(part1(&towels), 6);
+    assert_eq!(part2(&towels), 16);
+}
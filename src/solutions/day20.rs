@@ -0,0 +1,144 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::grid::Grid;
+use crate::util::point::Point;
+use std::collections::VecDeque;
+
+pub struct Day20;
+
+impl_day!(Day20, Racetrack, 20, "Race Condition");
+
+pub fn parse(input: &str) -> Result<Racetrack, Error> {
+    let tiles = Grid::parse(input)?;
+
+    let (mut start, mut end) = (None, None);
+    for (j, line) in input.lines().enumerate() {
+        for (i, c) in line.chars().enumerate() {
+            match c {
+                '#' | '.' => (),
+                'S' if start.is_none() => start = Some(Point::new(i as i32, j as i32)),
+                'E' if end.is_none() => end = Some(Point::new(i as i32, j as i32)),
+                _ => {
+                    return Err(Error::new(
+                        j + 1,
+                        i + 1,
+                        "expected one of '#', '.', 'S', 'E'",
+                    ))
+                }
+            }
+        }
+    }
+    let (Some(start), Some(_)) = (start, end) else {
+        return Err(Error::new(
+            1,
+            1,
+            "missing start position 'S' or end position 'E'",
+        ));
+    };
+
+    let mut picoseconds = tiles.map(|_| None);
+    picoseconds[start] = Some(0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((p, t)) = queue.pop_front() {
+        for nxt in tiles.neighbours4(p) {
+            if tiles[nxt] != b'#' && picoseconds[nxt].is_none() {
+                picoseconds[nxt] = Some(t + 1);
+                queue.push_back((nxt, t + 1));
+            }
+        }
+    }
+
+    Ok(Racetrack { picoseconds })
+}
+
+pub fn part1(track: &Racetrack) -> usize {
+    cheats(track, 2, THRESHOLD)
+}
+
+pub fn part2(track: &Racetrack) -> usize {
+    cheats(track, 20, THRESHOLD)
+}
+
+const THRESHOLD: usize = 100;
+
+// Number of cheats lasting at most 'duration' picoseconds which save at least 'threshold'
+// picoseconds.
+//
+// A cheat goes straight through walls, so it lasts as long as the Manhattan distance between its
+// start and end positions. Every cheat is found once, from its start position, by looking at all
+// the positions within reach.
+//
+// As stated by the puzzle, the racetrack is a single path from 'S' to 'E', so the time saved by a
+// cheat is the difference between the times to reach its end and its start.
+pub fn cheats(track: &Racetrack, duration: usize, threshold: usize) -> usize {
+    let picoseconds = &track.picoseconds;
+    let reach = duration as i32;
+
+    let mut count = 0;
+    for p in picoseconds.points() {
+        let Some(t) = picoseconds[p] else {
+            continue;
+        };
+
+        for dy in -reach..=reach {
+            let reach_x = reach - dy.abs();
+            for dx in -reach_x..=reach_x {
+                let q = p + Point::new(dx, dy);
+                if q == p {
+                    continue;
+                }
+                if let Some(Some(end)) = picoseconds.get(q) {
+                    if *end >= t + p.manhattan(q) as usize + threshold {
+                        count += 1;
+                    }
+                }
+            }
+        }
+    }
+    count
+}
+
+pub struct Racetrack {
+    picoseconds: Grid<Option<usize>>, // time to reach each position without cheating
+}
+
+#[test]
+fn sample_input() {
+    let input = "\
+        ###############\n\
+        #...#...#.....#\n\
+        #.#.#.#.#.###.#\n\
+        #S#...#.#.#...#\n\
+        #######.#.#.###\n\
+        #######.#.#...#\n\
+        #######.#.###.#\n\
+        ###..E#...#...#\n\
+        ###.#######.###\n\
+        #...###...#...#\n\
+        #.#####.#.###.#\n\
+        #.#...#.#.#...#\n\
+        #.#.#.#.#.#.###\n\
+        #...#...#...###\n\
+        ###############\n\
+        ";
+    let track = parse(input).unwrap();
+    assert_eq!(cheats(&track, 2, 64), 1);
+    assert_eq!(cheats(&track, 2, 20), 5);
+    assert_eq!(cheats(&track, 20, 76), 3);
+    assert_eq!(cheats(&track, 20, 74), 7);
+    assert_eq!(cheats(&track, 20, 50), 285);
+}
+
+#[test]
+fn invalid_input() {
+    let error = |input: &str| parse(input).err().map(|e| (e.line, e.column));
+    assert_eq!(error("#S.#\n"), Some((1, 1)));
+    assert_eq!(error("#S.E\n#E.#\n"), Some((2, 2)));
+    assert_eq!(error("#S.E\n#S.#\n"), Some((2, 2)));
+}
+
+#[test]
+fn zero_threshold() {
+    let track = parse("S.E\n").unwrap();
+    assert_eq!(cheats(&track, 2, 0), 3);
+}
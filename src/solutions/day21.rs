@@ -0,0 +1,165 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::parse::ParseOps;
+
+pub struct Day21;
+
+impl_day!(Day21, Vec<Code>, 21, "Keypad Conundrum");
+
+pub fn parse(input: &str) -> Result<Vec<Code>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(j, l)| {
+            if let Some(i) = l.find(|c: char| !c.is_ascii_digit() && c != 'A') {
+                return Err(Error::new(j + 1, i + 1, "expected a digit or 'A'"));
+            }
+            Ok(Code {
+                keys: l.bytes().collect(),
+                value: l
+                    .unsigned()
+                    .transpose()
+                    .map_err(|e| e.below(j))?
+                    .unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+pub fn part1(codes: &[Code]) -> u64 {
+    complexity(codes, 2)
+}
+
+pub fn part2(codes: &[Code]) -> u64 {
+    complexity(codes, 25)
+}
+
+// Sum of the complexities of the codes, when typed through the given number of robots which
+// operate directional keypads, followed by the robot which operates the numeric keypad.
+pub fn complexity(codes: &[Code], robots: usize) -> u64 {
+    // Cost of pressing each directional key after another, for the robot operating the numeric
+    // keypad. The human pushes the keys of the first directional keypad directly, then each robot
+    // in the chain relies on the costs of the keypad it is driven from.
+    let mut costs = [[1; 5]; 5];
+    for _ in 0..robots {
+        costs = layer(&costs);
+    }
+
+    codes
+        .iter()
+        .map(|code| {
+            let (presses, _) = code.keys.iter().fold((0, b'A'), |(presses, from), to| {
+                let moves = moves(numeric(from), numeric(*to), NUMERIC_GAP);
+                (presses + min_cost(moves, &costs), *to)
+            });
+            presses * code.value
+        })
+        .sum()
+}
+
+pub struct Code {
+    keys: Vec<u8>,
+    value: u64,
+}
+
+// Cost of moving from each directional key to another and pressing it, given the costs of the
+// keypad used to drive the moves.
+fn layer(costs: &[[u64; 5]; 5]) -> [[u64; 5]; 5] {
+    let mut next = [[0; 5]; 5];
+    for (from, row) in next.iter_mut().enumerate() {
+        for (to, cost) in row.iter_mut().enumerate() {
+            let moves = moves(DIRECTIONAL[from], DIRECTIONAL[to], DIRECTIONAL_GAP);
+            *cost = min_cost(moves, costs);
+        }
+    }
+    next
+}
+
+// Lowest cost among the sequences of directional keys.
+fn min_cost(sequences: impl Iterator<Item = Vec<usize>>, costs: &[[u64; 5]; 5]) -> u64 {
+    sequences
+        .map(|keys| {
+            let (cost, _) = keys.iter().fold((0, ACTIVATE), |(cost, from), to| {
+                (cost + costs[from][*to], *to)
+            });
+            cost
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+// Sequences of directional keys which move an arm between two keys, then press the latter.
+//
+// Changing direction as few times as possible is always cheaper for the robots up the chain, so
+// the only candidates are all horizontal moves followed by all vertical moves, and the reverse,
+// unless they would move the arm over the gap.
+fn moves(
+    (x1, y1): (i32, i32),
+    (x2, y2): (i32, i32),
+    gap: (i32, i32),
+) -> impl Iterator<Item = Vec<usize>> {
+    let horizontal = if x2 < x1 { LEFT } else { RIGHT };
+    let vertical = if y2 < y1 { UP } else { DOWN };
+    let horizontal = vec![horizontal; x1.abs_diff(x2) as usize];
+    let vertical = vec![vertical; y1.abs_diff(y2) as usize];
+
+    let horizontal_first = [horizontal.clone(), vertical.clone(), vec![ACTIVATE]].concat();
+    let vertical_first = [vertical, horizontal, vec![ACTIVATE]].concat();
+    [
+        ((x2, y1) != gap).then_some(horizontal_first),
+        ((x1, y2) != gap).then_some(vertical_first),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+// Directional keys, by index.
+const UP: usize = 0;
+const ACTIVATE: usize = 1;
+const LEFT: usize = 2;
+const DOWN: usize = 3;
+const RIGHT: usize = 4;
+
+//     +---+---+
+//     | ^ | A |
+// +---+---+---+
+// | < | v | > |
+// +---+---+---+
+const DIRECTIONAL: [(i32, i32); 5] = [(1, 0), (2, 0), (0, 1), (1, 1), (2, 1)];
+const DIRECTIONAL_GAP: (i32, i32) = (0, 0);
+
+// +---+---+---+
+// | 7 | 8 | 9 |
+// +---+---+---+
+// | 4 | 5 | 6 |
+// +---+---+---+
+// | 1 | 2 | 3 |
+// +---+---+---+
+//     | 0 | A |
+//     +---+---+
+fn numeric(key: u8) -> (i32, i32) {
+    match key {
+        b'0' => (1, 3),
+        b'1'..=b'9' => {
+            let n = (key - b'1') as i32;
+            (n % 3, 2 - n / 3)
+        }
+        _ => (2, 3),
+    }
+}
+const NUMERIC_GAP: (i32, i32) = (0, 3);
+
+#[test]
+fn sample_input() {
+    let input = "\
+        029A\n\
+        980A\n\
+        179A\n\
+        456A\n\
+        379A\n\
+        ";
+    let codes = parse(input).unwrap();
+    assert_eq!(complexity(&codes[..1], 2), 68 * 29);
+    assert_eq!(part1(&codes), 126384);
+    assert_eq!(part2(&codes), 154_115_708_116_294);
+}
@@ -0,0 +1,98 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use crate::util::parse::ParseOps;
+
+pub struct Day22;
+
+impl_day!(Day22, Vec<u64>, 22, "Monkey Market");
+
+pub fn parse(input: &str) -> Result<Vec<u64>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(j, l)| match l.find(|c: char| !c.is_ascii_digit()) {
+            Some(i) => Err(Error::new(j + 1, i + 1, "expected a secret number")),
+            None => l
+                .unsigned()
+                .ok_or_else(|| Error::new(j + 1, 1, "expected a secret number"))?
+                .map_err(|e| e.below(j)),
+        })
+        .collect()
+}
+
+pub fn part1(secrets: &[u64]) -> u64 {
+    secrets
+        .iter()
+        .map(|s| (0..SECRETS).fold(*s, |s, _| next(s)))
+        .sum()
+}
+
+pub fn part2(secrets: &[u64]) -> u32 {
+    // Each change is in -9..=9, so a sequence of four changes is encoded as a 4-digit number in
+    // base 19, which indexes the total number of bananas it earns.
+    let mut bananas = vec![0u32; SEQUENCES];
+    // Index, plus one, of the last buyer who sold on each sequence, as a buyer only sells the first
+    // time a sequence occurs.
+    let mut sold = vec![0usize; SEQUENCES];
+
+    for (buyer, secret) in secrets.iter().enumerate() {
+        let mut secret = *secret;
+        let mut price = secret % 10;
+        let mut sequence = 0;
+        for n in 0..SECRETS {
+            secret = next(secret);
+            let new_price = secret % 10;
+            let change = (9 + new_price - price) as usize;
+            sequence = (sequence * 19 + change) % SEQUENCES;
+            price = new_price;
+
+            if n >= 3 && sold[sequence] != buyer + 1 {
+                sold[sequence] = buyer + 1;
+                bananas[sequence] += price as u32;
+            }
+        }
+    }
+    bananas.into_iter().max().unwrap_or_default()
+}
+
+const SECRETS: usize = 2000;
+const SEQUENCES: usize = 19 * 19 * 19 * 19;
+
+fn next(secret: u64) -> u64 {
+    let secret = ((secret << 6) ^ secret) % 16_777_216;
+    let secret = ((secret >> 5) ^ secret) % 16_777_216;
+    ((secret << 11) ^ secret) % 16_777_216
+}
+
+#[test]
+fn sample_input_1() {
+    let input = "\
+        1\n\
+        10\n\
+        100\n\
+        2024\n\
+        ";
+    let secrets = parse(input).unwrap();
+    assert_eq!(part1(&secrets), 37327623);
+}
+
+#[test]
+fn sample_input_2() {
+    let input = "\
+        1\n\
+        2\n\
+        3\n\
+        2024\n\
+        ";
+    let secrets = parse(input).unwrap();
+    assert_eq!(part2(&secrets), 23);
+}
+
+#[test]
+fn secret_sequence() {
+    let secrets: Vec<u64> = std::iter::successors(Some(123), |s| Some(next(*s)))
+        .skip(1)
+        .take(3)
+        .collect();
+    assert_eq!(secrets, [15887950, 16495136, 527345]);
+}
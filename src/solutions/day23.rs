@@ -0,0 +1,178 @@
+use crate::day::impl_day;
+use crate::error::Error;
+use petgraph::graph::NodeIndex;
+use petgraph::{Graph, Undirected};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day23;
+
+impl_day!(Day23, Network, 23, "LAN Party");
+
+pub fn parse(input: &str) -> Result<Network, Error> {
+    let mut graph = Graph::default();
+    let mut nodes = HashMap::new();
+
+    for (j, line) in input.lines().enumerate() {
+        let Some((a, b)) = line
+            .split_once('-')
+            .filter(|(a, b)| !a.is_empty() && !b.is_empty())
+        else {
+            return Err(Error::new(
+                j + 1,
+                1,
+                "expected a connection between two computers",
+            ));
+        };
+        if let Some(i) = line.find(|c: char| !c.is_ascii_lowercase() && c != '-') {
+            return Err(Error::new(j + 1, i + 1, "expected the name of a computer"));
+        }
+        if a == b {
+            return Err(Error::new(
+                j + 1,
+                a.len() + 2,
+                "expected a connection to another computer",
+            ));
+        }
+
+        let mut node = |name: &str| {
+            *nodes
+                .entry(name.to_string())
+                .or_insert_with(|| graph.add_node(name.to_string()))
+        };
+        let (a, b) = (node(a), node(b));
+        graph.update_edge(a, b, ());
+    }
+
+    Ok(Network { graph })
+}
+
+pub fn part1(network: &Network) -> usize {
+    let graph = &network.graph;
+
+    // Each triangle is found once, from its computers in increasing order of index.
+    let mut count = 0;
+    for a in graph.node_indices() {
+        for b in graph.neighbors(a).filter(|b| *b > a) {
+            for c in graph.neighbors(b).filter(|c| *c > b) {
+                if graph.contains_edge(a, c) && [a, b, c].iter().any(|n| graph[*n].starts_with('t'))
+                {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+pub fn part2(network: &Network) -> String {
+    let graph = &network.graph;
+
+    let mut largest = Vec::new();
+    bron_kerbosch(
+        graph,
+        &mut Vec::new(),
+        graph.node_indices().collect(),
+        HashSet::new(),
+        &mut largest,
+    );
+
+    let mut names: Vec<&str> = largest.iter().map(|n| graph[*n].as_str()).collect();
+    names.sort_unstable();
+    names.join(",")
+}
+
+pub struct Network {
+    graph: Graph<String, (), Undirected, usize>,
+}
+
+// Bron–Kerbosch algorithm with pivoting, which enumerates the maximal cliques extending 'clique'
+// with computers from 'candidates', but not from 'excluded', and keeps the largest one.
+fn bron_kerbosch(
+    graph: &Graph<String, (), Undirected, usize>,
+    clique: &mut Vec<NodeIndex<usize>>,
+    mut candidates: HashSet<NodeIndex<usize>>,
+    mut excluded: HashSet<NodeIndex<usize>>,
+    largest: &mut Vec<NodeIndex<usize>>,
+) {
+    if candidates.is_empty() && excluded.is_empty() {
+        if clique.len() > largest.len() {
+            *largest = clique.clone();
+        }
+        return;
+    }
+
+    // Neighbours of the pivot are found through another candidate, so only the other candidates
+    // are tried at this level. Picking the pivot with the most neighbours skips the most.
+    let Some(pivot) = candidates
+        .union(&excluded)
+        .max_by_key(|n| graph.neighbors(**n).count())
+        .copied()
+    else {
+        return;
+    };
+    let pivot_neighbours: HashSet<_> = graph.neighbors(pivot).collect();
+    let tried: Vec<_> = candidates.difference(&pivot_neighbours).copied().collect();
+
+    for n in tried {
+        let neighbours: HashSet<_> = graph.neighbors(n).collect();
+        clique.push(n);
+        bron_kerbosch(
+            graph,
+            clique,
+            candidates.intersection(&neighbours).copied().collect(),
+            excluded.intersection(&neighbours).copied().collect(),
+            largest,
+        );
+        clique.pop();
+
+        candidates.remove(&n);
+        excluded.insert(n);
+    }
+}
+
+#[test]
+fn sample_input() {
+    let input = "\
+        kh-tc\n\
+        qp-kh\n\
+        de-cg\n\
+        ka-co\n\
+        yn-aq\n\
+        qp-ub\n\
+        cg-tb\n\
+        vc-aq\n\
+        tb-ka\n\
+        wh-tc\n\
+        yn-cg\n\
+        kh-ub\n\
+        ta-co\n\
+        de-co\n\
+        tc-td\n\
+        tb-wq\n\
+        wh-td\n\
+        ta-ka\n\
+        td-qp\n\
+        aq-cg\n\
+        wq-ub\n\
+        ub-vc\n\
+        de-ta\n\
+        wq-aq\n\
+        wq-vc\n\
+        wh-yn\n\
+        ka-de\n\
+        kh-ta\n\
+        co-tc\n\
+        wh-qp\n\
+        tb-vc\n\
+        td-yn\n\
+        ";
+    let network = parse(input).unwrap();
+    assert_eq!(part1(&network), 7);
+    assert_eq!(part2(&network), "co,de,ka,ta");
+}
+
+#[test]
+fn self_connection() {
+    let error = parse("bb-aa\naa-aa\n").err();
+    assert_eq!(error.map(|e| (e.line, e.column)), Some((2, 4)));
+}
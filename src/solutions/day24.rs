@@ -0,0 +1,370 @@
+use crate::day::{Answer, Day};
+use crate::error::Error;
+use petgraph::dot::{Config, Dot};
+use petgraph::Graph;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+pub struct Day24;
+
+impl Day for Day24 {
+    type Input = Circuit;
+
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Crossed Wires";
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        Answer::new(part1(input), "some bits of z have no signal")
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        Answer::new(part2(input), "the swapped wires can't be told apart")
+    }
+}
+
+pub fn parse(input: &str) -> Result<Circuit, Error> {
+    let (wires, gates) = input.split_once("\n\n").unwrap_or((input, ""));
+
+    let wires = wires
+        .lines()
+        .enumerate()
+        .map(|(j, l)| match l.split_once(": ") {
+            Some((wire, "0")) => Ok((wire.to_string(), false)),
+            Some((wire, "1")) => Ok((wire.to_string(), true)),
+            _ => Err(Error::new(j + 1, 1, "expected a wire and its value")),
+        })
+        .collect::<Result<_, _>>()?;
+
+    // The gates start after the wires and an empty line.
+    let first_line = input.lines().take_while(|l| !l.is_empty()).count() + 2;
+    let gates = gates
+        .lines()
+        .enumerate()
+        .map(|(j, l)| {
+            let fields: Vec<&str> = l.split(' ').collect();
+            let [a, op, b, "->", output] = fields[..] else {
+                return Err(Error::new(first_line + j, 1, "expected a gate"));
+            };
+            let op = match op {
+                "AND" => Op::And,
+                "OR" => Op::Or,
+                "XOR" => Op::Xor,
+                _ => {
+                    return Err(Error::new(
+                        first_line + j,
+                        a.len() + 2,
+                        "expected one of 'AND', 'OR', 'XOR'",
+                    ))
+                }
+            };
+            Ok(Gate {
+                inputs: [a.to_string(), b.to_string()],
+                op,
+                output: output.to_string(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Circuit { wires, gates })
+}
+
+pub fn part1(circuit: &Circuit) -> Option<u64> {
+    let values = circuit.evaluate();
+    let z_outputs = circuit.gates.iter().map(|g| g.output.as_str());
+    if !z_outputs
+        .filter(|w| w.starts_with('z'))
+        .all(|w| values.contains_key(w))
+    {
+        return None;
+    }
+
+    // Bits are numbered from the least significant one.
+    let mut z: Vec<(&str, bool)> = values
+        .into_iter()
+        .filter(|(wire, _)| wire.starts_with('z'))
+        .collect();
+    z.sort_unstable();
+    Some(
+        z.iter()
+            .rev()
+            .fold(0, |n, (_, bit)| (n << 1) | (*bit as u64)),
+    )
+}
+
+// Four pairs of gates have their outputs swapped.
+pub fn part2(circuit: &Circuit) -> Option<String> {
+    let swapped = swapped_wires(circuit);
+    (swapped.len() == 8).then(|| swapped.join(","))
+}
+
+// The circuit is meant to be a ripple-carry adder, which adds the x and y numbers into z. Each bit
+// of the adder, except the first one which has no carry in, is made of these gates:
+//
+//   x XOR y -> sum            x AND y -> carry1
+//   sum XOR carry_in -> z     sum AND carry_in -> carry2
+//   carry1 OR carry2 -> carry_out
+//
+// and the carry out of the last bit is the highest bit of z. Swapped outputs are found by checking
+// each gate against the role it would have in that structure.
+pub fn swapped_wires(circuit: &Circuit) -> Vec<&str> {
+    let last_z = circuit
+        .gates
+        .iter()
+        .map(|g| g.output.as_str())
+        .filter(|w| w.starts_with('z'))
+        .max()
+        .unwrap_or_default();
+
+    // Operations of the gates each wire is an input of.
+    let mut consumers: HashMap<&str, Vec<Op>> = HashMap::new();
+    for gate in &circuit.gates {
+        for input in &gate.inputs {
+            consumers.entry(input).or_default().push(gate.op);
+        }
+    }
+    let feeds = |wire: &str, op: Op| consumers.get(wire).is_some_and(|ops| ops.contains(&op));
+
+    let mut swapped: Vec<&str> = circuit
+        .gates
+        .iter()
+        .filter(|gate| {
+            let output = gate.output.as_str();
+            let from_inputs = gate.inputs.iter().all(|w| w.starts_with(['x', 'y']));
+            let first_bit = gate.inputs.iter().any(|w| w == "x00");
+            match gate.op {
+                // Every bit of z, except the last carry, is a sum.
+                _ if output.starts_with('z') && output != last_z => gate.op != Op::Xor,
+                Op::Xor if from_inputs => !first_bit && !feeds(output, Op::Xor),
+                Op::Xor => !output.starts_with('z'),
+                // The carry of the first bit is an input of the second bit like any carry out.
+                Op::And => !first_bit && !feeds(output, Op::Or),
+                Op::Or => false,
+            }
+        })
+        .map(|gate| gate.output.as_str())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    swapped.sort_unstable();
+    swapped
+}
+
+pub struct Circuit {
+    wires: Vec<(String, bool)>, // initial values
+    gates: Vec<Gate>,
+}
+
+impl Circuit {
+    // Values of all the wires, once the signals propagated through every gate they can reach.
+    fn evaluate(&self) -> HashMap<&str, bool> {
+        let mut values: HashMap<&str, bool> =
+            self.wires.iter().map(|(w, v)| (w.as_str(), *v)).collect();
+
+        let mut pending: Vec<&Gate> = self.gates.iter().collect();
+        while !pending.is_empty() {
+            let before = pending.len();
+            pending.retain(|gate| {
+                let [a, b] = &gate.inputs;
+                let (Some(a), Some(b)) = (values.get(a.as_str()), values.get(b.as_str())) else {
+                    return true;
+                };
+                let value = match gate.op {
+                    Op::And => a & b,
+                    Op::Or => a | b,
+                    Op::Xor => a ^ b,
+                };
+                values.insert(&gate.output, value);
+                false
+            });
+            // The remaining gates are part of a loop, or depend on a wire without a signal.
+            if pending.len() == before {
+                break;
+            }
+        }
+        values
+    }
+
+    /// Exports the circuit in the Graphviz DOT format, with a node for each wire and each gate.
+    pub fn dot(&self) -> String {
+        let mut graph = Graph::<String, &str>::new();
+        let mut wires = HashMap::new();
+        let mut wire = |graph: &mut Graph<String, &str>, name: &str| {
+            *wires
+                .entry(name.to_string())
+                .or_insert_with(|| graph.add_node(name.to_string()))
+        };
+
+        for (name, _) in &self.wires {
+            wire(&mut graph, name);
+        }
+        for gate in &self.gates {
+            let node = graph.add_node(format!("{:?}", gate.op).to_uppercase());
+            for input in &gate.inputs {
+                let input = wire(&mut graph, input);
+                graph.add_edge(input, node, "");
+            }
+            let output = wire(&mut graph, &gate.output);
+            graph.add_edge(node, output, "");
+        }
+
+        Dot::with_config(&graph, &[Config::EdgeNoLabel]).to_string()
+    }
+}
+
+struct Gate {
+    inputs: [String; 2],
+    op: Op,
+    output: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+#[test]
+fn sample_input_1() {
+    let input = "\
+        x00: 1\n\
+        x01: 1\n\
+        x02: 1\n\
+        y00: 0\n\
+        y01: 1\n\
+        y02: 0\n\
+        \n\
+        x00 AND y00 -> z00\n\
+        x01 XOR y01 -> z01\n\
+        x02 OR y02 -> z02\n\
+        ";
+    let circuit = parse(input).unwrap();
+    assert_eq!(part1(&circuit), Some(4));
+}
+
+#[test]
+fn sample_input_2() {
+    let input = "\
+        x00: 1\n\
+        x01: 0\n\
+        x02: 1\n\
+        x03: 1\n\
+        x04: 0\n\
+        y00: 1\n\
+        y01: 1\n\
+        y02: 1\n\
+        y03: 1\n\
+        y04: 1\n\
+        \n\
+        ntg XOR fgs -> mjb\n\
+        y02 OR x01 -> tnw\n\
+        kwq OR kpj -> z05\n\
+        x00 OR x03 -> fst\n\
+        tgd XOR rvg -> z01\n\
+        vdt OR tnw -> bfw\n\
+        bfw AND frj -> z10\n\
+        ffh OR nrd -> bqk\n\
+        y00 AND y03 -> djm\n\
+        y03 OR y00 -> psh\n\
+        bqk OR frj -> z08\n\
+        tnw OR fst -> frj\n\
+        gnj AND tgd -> z11\n\
+        bfw XOR mjb -> z00\n\
+        x03 OR x00 -> vdt\n\
+        gnj AND wpb -> z02\n\
+        x04 AND y00 -> kjc\n\
+        djm OR pbm -> qhw\n\
+        nrd AND vdt -> hwm\n\
+        kjc AND fst -> rvg\n\
+        y04 OR y02 -> fgs\n\
+        y01 AND x02 -> pbm\n\
+        ntg OR kjc -> kwq\n\
+        psh XOR fgs -> tgd\n\
+        qhw XOR tgd -> z09\n\
+        pbm OR djm -> kpj\n\
+        x03 XOR y03 -> ffh\n\
+        x00 XOR y04 -> ntg\n\
+        bfw OR bqk -> z06\n\
+        nrd XOR fgs -> wpb\n\
+        frj XOR qhw -> z04\n\
+        bqk OR frj -> z07\n\
+        y03 OR x01 -> nrd\n\
+        hwm AND bqk -> z03\n\
+        tgd XOR rvg -> z12\n\
+        tnw OR pbm -> gnj\n\
+        ";
+    let circuit = parse(input).unwrap();
+    assert_eq!(part1(&circuit), Some(2024));
+}
+
+#[test]
+fn swapped_adder() {
+    // 3-bit adder where the sum and carry of x02 and y02 are swapped, as well as z01 and the
+    // carry out of bit 1.
+    let input = "\
+        x00: 1\n\
+        x01: 1\n\
+        x02: 1\n\
+        y00: 1\n\
+        y01: 0\n\
+        y02: 1\n\
+        \n\
+        x00 XOR y00 -> z00\n\
+        x00 AND y00 -> c00\n\
+        x01 XOR y01 -> s01\n\
+        x01 AND y01 -> a01\n\
+        s01 XOR c00 -> c01\n\
+        s01 AND c00 -> b01\n\
+        a01 OR b01 -> z01\n\
+        x02 XOR y02 -> a02\n\
+        x02 AND y02 -> s02\n\
+        s02 XOR c01 -> z02\n\
+        s02 AND c01 -> b02\n\
+        a02 OR b02 -> z03\n\
+        ";
+    let circuit = parse(input).unwrap();
+    assert_eq!(swapped_wires(&circuit), ["a02", "c01", "s02", "z01"]);
+    // Only two pairs are swapped, where the puzzle expects four.
+    assert_eq!(part2(&circuit), None);
+}
+
+#[test]
+fn dot_export() {
+    let input = "\
+        x00: 1\n\
+        y00: 0\n\
+        \n\
+        x00 AND y00 -> z00\n\
+        ";
+    let circuit = parse(input).unwrap();
+    assert_eq!(
+        circuit.dot(),
+        "\
+        digraph {\n    \
+            0 [ label = \"x00\" ]\n    \
+            1 [ label = \"y00\" ]\n    \
+            2 [ label = \"AND\" ]\n    \
+            3 [ label = \"z00\" ]\n    \
+            0 -> 2 [ ]\n    \
+            1 -> 2 [ ]\n    \
+            2 -> 3 [ ]\n\
+        }\n\
+        "
+    );
+}
+
+#[test]
+fn missing_signal() {
+    let input = "\
+        x00: 1\n\
+        \n\
+        x00 AND y00 -> z00\n\
+        x00 OR x00 -> z01\n\
+        ";
+    let circuit = parse(input).unwrap();
+    assert_eq!(part1(&circuit), None);
+}
@@ -0,0 +1,145 @@
+use crate::day::{Day, Parts};
+use crate::error::Error;
+use std::fmt::Display;
+
+pub struct Day25;
+
+impl Day for Day25 {
+    type Input = Schematics;
+
+    const DAY: u32 = 25;
+    const TITLE: &'static str = "Code Chronicle";
+    const PARTS: Parts = Parts::Part1;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    // Never called, as there is no second part.
+    fn part2(_: &Self::Input) -> impl Display {
+        ""
+    }
+}
+
+pub fn parse(input: &str) -> Result<Schematics, Error> {
+    let mut schematics = Schematics::default();
+
+    let mut line = 1;
+    for block in input.split("\n\n") {
+        let rows: Vec<&str> = block.lines().collect();
+        if rows.len() != HEIGHT + 2 {
+            return Err(Error::new(
+                line,
+                1,
+                format!("expected {} rows, found {}", HEIGHT + 2, rows.len()),
+            ));
+        }
+
+        let mut heights = [0; WIDTH];
+        for (j, row) in rows.iter().enumerate() {
+            if row.len() != WIDTH {
+                return Err(Error::new(
+                    line + j,
+                    row.len().min(WIDTH) + 1,
+                    format!("expected {WIDTH} columns, found {}", row.len()),
+                ));
+            }
+            for (i, c) in row.chars().enumerate() {
+                match c {
+                    '#' => heights[i] += 1,
+                    '.' => (),
+                    _ => return Err(Error::new(line + j, i + 1, "expected '#' or '.'")),
+                }
+            }
+        }
+
+        // Either the top row or the bottom row is full, and doesn't count in the heights.
+        let full = |row: &str| row.bytes().all(|b| b == b'#');
+        let schematic = if full(rows[0]) {
+            &mut schematics.locks
+        } else if full(rows[HEIGHT + 1]) {
+            &mut schematics.keys
+        } else {
+            return Err(Error::new(line, 1, "expected a lock or a key"));
+        };
+        schematic.push(heights.map(|h| h - 1));
+
+        line += rows.len() + 1;
+    }
+
+    Ok(schematics)
+}
+
+pub fn part1(schematics: &Schematics) -> usize {
+    let Schematics { locks, keys } = schematics;
+    locks
+        .iter()
+        .flat_map(|lock| keys.iter().map(move |key| (lock, key)))
+        .filter(|(lock, key)| lock.iter().zip(key.iter()).all(|(l, k)| l + k <= HEIGHT))
+        .count()
+}
+
+const WIDTH: usize = 5;
+const HEIGHT: usize = 5; // available space, without the full rows at the top and bottom
+
+#[derive(Default)]
+pub struct Schematics {
+    locks: Vec<[usize; WIDTH]>, // height of each pin
+    keys: Vec<[usize; WIDTH]>,  // height of each column
+}
+
+#[test]
+fn sample_input() {
+    let input = "\
+        #####\n\
+        .####\n\
+        .####\n\
+        .####\n\
+        .#.#.\n\
+        .#...\n\
+        .....\n\
+        \n\
+        #####\n\
+        ##.##\n\
+        .#.##\n\
+        ...##\n\
+        ...#.\n\
+        ...#.\n\
+        .....\n\
+        \n\
+        .....\n\
+        #....\n\
+        #....\n\
+        #...#\n\
+        #.#.#\n\
+        #.###\n\
+        #####\n\
+        \n\
+        .....\n\
+        .....\n\
+        #.#..\n\
+        ###..\n\
+        ###.#\n\
+        ###.#\n\
+        #####\n\
+        \n\
+        .....\n\
+        .....\n\
+        .....\n\
+        #....\n\
+        #.#..\n\
+        #.#.#\n\
+        #####\n\
+        ";
+    let schematics = parse(input).unwrap();
+    assert_eq!(schematics.locks, [[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]]);
+    assert_eq!(
+        schematics.keys,
+        [[5, 0, 2, 1, 3], [4, 3, 4, 0, 2], [3, 0, 2, 0, 1]]
+    );
+    assert_eq!(part1(&schematics), 3);
+}
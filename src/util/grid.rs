@@ -0,0 +1,159 @@
+use crate::error::Error;
+use crate::util::point::{Direction, Direction8, Point};
+use std::ops::{Index, IndexMut};
+
+/// Rectangular map, stored row by row. Points are coordinates from the top-left corner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<u8> {
+    /// Parses a map of characters with one row per line, or returns the location of the first line
+    /// which breaks the rectangular shape.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let width = input.lines().next().map_or(0, str::len);
+
+        let mut cells = Vec::with_capacity(input.len());
+        for (j, line) in input.lines().enumerate() {
+            if line.len() != width {
+                return Err(Error::new(
+                    j + 1,
+                    line.len().min(width) + 1,
+                    format!("expected {width} columns, found {}", line.len()),
+                ));
+            }
+            cells.extend(line.bytes());
+        }
+
+        Ok(Self {
+            width,
+            height: input.lines().count(),
+            cells,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid of the same size, with the value of each cell converted.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn contains(&self, Point { x, y }: Point) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Sets the value of a cell. Panics if the point is out of bounds.
+    pub fn set(&mut self, point: Point, value: T) {
+        self[point] = value;
+    }
+
+    /// Position of the point in the row-by-row storage, if it is within bounds.
+    pub fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.x as usize + point.y as usize * self.width)
+    }
+
+    /// All the points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// First point, row by row, holding the given value.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.points().find(|p| self[*p] == *value)
+    }
+
+    /// Orthogonal neighbours within bounds, in clockwise order from the one above.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |d| point + d.delta())
+            .filter(|p| self.contains(*p))
+    }
+
+    /// Orthogonal and diagonal neighbours within bounds, in clockwise order from the one above.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .map(move |d| point + d.delta())
+            .filter(|p| self.contains(*p))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).expect("point out of bounds")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).expect("point out of bounds")
+    }
+}
+
+#[test]
+fn rectangular() {
+    let grid = Grid::parse("#..\n.^#\n").unwrap();
+    assert_eq!((grid.width, grid.height), (3, 2));
+    assert_eq!(grid.find(&b'^'), Some(Point::new(1, 1)));
+    assert_eq!(grid.get(Point::new(2, 1)), Some(&b'#'));
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+
+    let rows: Vec<&[u8]> = grid.rows().collect();
+    assert_eq!(rows, [b"#..", b".^#"]);
+    let columns: Vec<Vec<u8>> = grid.columns().map(|c| c.copied().collect()).collect();
+    assert_eq!(columns, [b"#.", b".^", b".#"]);
+
+    assert_eq!(
+        grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+        [Point::new(1, 0), Point::new(0, 1)]
+    );
+    assert_eq!(grid.neighbours8(Point::new(2, 0)).count(), 3);
+
+    assert_eq!(
+        Grid::parse("#..\n.^\n").err(),
+        Some(Error::new(2, 3, "expected 3 columns, found 2"))
+    );
+}
@@ -1,15 +1,56 @@
-use std::ops::{Add, Mul};
+use std::ops::{Add, Mul, Neg};
 
 pub trait Unsigned<T>: Copy + From<u8> + Add<Output = T> + Mul<Output = T> {
     const TEN: T;
+
+    fn checked_add(self, rhs: T) -> Option<T>;
+    fn checked_mul(self, rhs: T) -> Option<T>;
 }
 
 macro_rules! unsigned {
     ($($t:ty)*) => ($(
         impl Unsigned<$t> for $t {
             const TEN: $t = 10;
+
+            fn checked_add(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_mul(self, rhs)
+            }
         }
     )*)
 }
 
 unsigned!(u32 u64);
+
+pub trait Signed<T>: Copy + From<u8> + Add<Output = T> + Mul<Output = T> + Neg<Output = T> {
+    const TEN: T;
+
+    fn checked_add(self, rhs: T) -> Option<T>;
+    fn checked_sub(self, rhs: T) -> Option<T>;
+    fn checked_mul(self, rhs: T) -> Option<T>;
+}
+
+macro_rules! signed {
+    ($($t:ty)*) => ($(
+        impl Signed<$t> for $t {
+            const TEN: $t = 10;
+
+            fn checked_add(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_mul(self, rhs)
+            }
+        }
+    )*)
+}
+
+signed!(i32 i64);
@@ -1,6 +1,6 @@
-use crate::util::integer::Unsigned;
+use crate::error::Error;
+use crate::util::integer::{Signed, Unsigned};
 use std::marker::PhantomData;
-use std::str::Bytes;
 
 pub trait ParseByte {
     fn to_decimal(self) -> u8;
@@ -13,65 +13,177 @@ impl ParseByte for u8 {
     }
 }
 
+/// Iterator over the unsigned numbers of a text, which yields an error for a number too large for
+/// `T`. Lines and columns of the error are relative to the start of the text.
 pub struct ParseUnsigned<'a, T> {
-    bytes: Bytes<'a>,
+    cursor: Cursor<'a>,
+    phantom: PhantomData<T>,
+}
+
+/// Iterator over the signed numbers of a text, like [`ParseUnsigned`].
+pub struct ParseSigned<'a, T> {
+    cursor: Cursor<'a>,
     phantom: PhantomData<T>,
 }
 
 pub trait ParseOps {
-    fn unsigned<T: Unsigned<T>>(&self) -> T;
+    fn unsigned<T: Unsigned<T>>(&self) -> Option<Result<T, Error>>;
     fn iter_unsigned<T: Unsigned<T>>(&self) -> ParseUnsigned<'_, T>;
+    fn iter_signed<T: Signed<T>>(&self) -> ParseSigned<'_, T>;
 }
 
 impl ParseOps for &str {
-    fn unsigned<T: Unsigned<T>>(&self) -> T {
-        match try_unsigned(&mut self.bytes()) {
-            Some(t) => t,
-            None => panic!("Unable to parse \"{self}\""),
-        }
+    fn unsigned<T: Unsigned<T>>(&self) -> Option<Result<T, Error>> {
+        self.iter_unsigned().next()
     }
 
     fn iter_unsigned<T: Unsigned<T>>(&self) -> ParseUnsigned<'_, T> {
         ParseUnsigned {
-            bytes: self.bytes(),
+            cursor: Cursor::new(self),
+            phantom: PhantomData,
+        }
+    }
+
+    fn iter_signed<T: Signed<T>>(&self) -> ParseSigned<'_, T> {
+        ParseSigned {
+            cursor: Cursor::new(self),
             phantom: PhantomData,
         }
     }
 }
 
 impl<T: Unsigned<T>> Iterator for ParseUnsigned<'_, T> {
-    type Item = T;
+    type Item = Result<T, Error>;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cursor.bytes.len() - self.cursor.index;
+        (0, Some(remaining.div_ceil(2)))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        try_unsigned(&mut self.cursor)
+    }
+}
+
+impl<T: Signed<T>> Iterator for ParseSigned<'_, T> {
+    type Item = Result<T, Error>;
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (lower, upper) = self.bytes.size_hint();
-        (lower / 3, upper.map(|u| u / 3))
+        let remaining = self.cursor.bytes.len() - self.cursor.index;
+        (0, Some(remaining.div_ceil(2)))
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        try_unsigned(&mut self.bytes)
+        try_signed(&mut self.cursor)
+    }
+}
+
+fn try_unsigned<T: Unsigned<T>>(cursor: &mut Cursor<'_>) -> Option<Result<T, Error>> {
+    let start = cursor.skip_to_digit()?;
+
+    let mut n = T::from(0);
+    while let Some(digit) = cursor.digit() {
+        match n
+            .checked_mul(T::TEN)
+            .and_then(|n| n.checked_add(T::from(digit)))
+        {
+            Some(next) => n = next,
+            None => return Some(Err(cursor.overflow(start))),
+        }
     }
+    Some(Ok(n))
 }
 
-fn try_unsigned<T: Unsigned<T>>(bytes: &mut Bytes<'_>) -> Option<T> {
-    let mut n = loop {
-        let byte = bytes.next()?;
-        let digit = byte.to_decimal();
+fn try_signed<T: Signed<T>>(cursor: &mut Cursor<'_>) -> Option<Result<T, Error>> {
+    let mut start = cursor.skip_to_digit()?;
+    // only a '-' immediately preceding the digits denotes a negative number
+    let negative = start > 0 && cursor.bytes[start - 1] == b'-';
+    if negative {
+        start -= 1;
+    }
 
-        if digit < 10 {
-            break T::from(digit);
+    // Negative numbers are accumulated as such, as their range is larger than the positive one.
+    let mut n = T::from(0);
+    while let Some(digit) = cursor.digit() {
+        let next = n.checked_mul(T::TEN).and_then(|n| match negative {
+            true => n.checked_sub(T::from(digit)),
+            false => n.checked_add(T::from(digit)),
+        });
+        match next {
+            Some(next) => n = next,
+            None => return Some(Err(cursor.overflow(start))),
         }
-    };
-
-    loop {
-        let Some(byte) = bytes.next() else {
-            break Some(n);
-        };
-        let digit = byte.to_decimal();
-
-        if digit < 10 {
-            n = T::TEN * n + T::from(digit);
-        } else {
-            break Some(n);
+    }
+    Some(Ok(n))
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            bytes: input.as_bytes(),
+            index: 0,
         }
     }
+
+    // Moves to the next digit, and returns its index.
+    fn skip_to_digit(&mut self) -> Option<usize> {
+        let offset = self.bytes[self.index..]
+            .iter()
+            .position(|b| b.to_decimal() < 10)?;
+        self.index += offset;
+        Some(self.index)
+    }
+
+    // Consumes the digit at the cursor, if any.
+    fn digit(&mut self) -> Option<u8> {
+        let digit = self.bytes.get(self.index)?.to_decimal();
+        (digit < 10).then(|| {
+            self.index += 1;
+            digit
+        })
+    }
+
+    // Error for the number starting at the given index, after skipping the rest of its digits.
+    fn overflow(&mut self, start: usize) -> Error {
+        while self.digit().is_some() {}
+
+        let before = &self.bytes[..start];
+        let line = before.iter().filter(|b| **b == b'\n').count() + 1;
+        let line_start = before
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map_or(0, |i| i + 1);
+        let column = start - line_start + 1;
+        Error::new(line, column, "number too large")
+    }
+}
+
+#[test]
+fn overflow() {
+    let numbers: Vec<_> = "1 99999999999 -3".iter_unsigned::<u32>().collect();
+    assert_eq!(
+        numbers,
+        [Ok(1), Err(Error::new(1, 3, "number too large")), Ok(3)]
+    );
+
+    let numbers: Vec<_> = "x=-2147483648\ny=2147483648".iter_signed::<i32>().collect();
+    assert_eq!(
+        numbers,
+        [Ok(i32::MIN), Err(Error::new(2, 3, "number too large"))]
+    );
+    assert_eq!("  42a".unsigned::<u64>(), Some(Ok(42)));
+}
+
+#[test]
+fn size_hint() {
+    for input in ["1 2", "abcdef", "-1,-2,3", ""] {
+        let (lower, upper) = input.iter_signed::<i32>().size_hint();
+        let count = input.iter_signed::<i32>().count();
+        assert!(lower <= count && upper.is_some_and(|u| count <= u));
+    }
 }
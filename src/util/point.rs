@@ -0,0 +1,193 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Point or vector on a grid. The y axis points down, as rows are numbered from the top.
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Rotates the vector by 90° counterclockwise, e.g. from ↑ to ←.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Rotates the vector by 90° clockwise, e.g. from ↑ to →.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn rotate_180(self) -> Self {
+        -self
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Orthogonal directions, in clockwise order.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Vector of a single step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+/// Orthogonal and diagonal directions, in clockwise order.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns by 45° counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns by 45° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Vector of a single step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Direction8::Up => Point::new(0, -1),
+            Direction8::UpRight => Point::new(1, -1),
+            Direction8::Right => Point::new(1, 0),
+            Direction8::DownRight => Point::new(1, 1),
+            Direction8::Down => Point::new(0, 1),
+            Direction8::DownLeft => Point::new(-1, 1),
+            Direction8::Left => Point::new(-1, 0),
+            Direction8::UpLeft => Point::new(-1, -1),
+        }
+    }
+}
+
+#[test]
+fn directions() {
+    for d in Direction::ALL {
+        assert_eq!(d.turn_right().delta(), d.delta().rotate_right());
+        assert_eq!(d.turn_left().delta(), d.delta().rotate_left());
+        assert_eq!(d.opposite().delta(), d.delta().rotate_180());
+        assert_eq!(d.turn_left().turn_right(), d);
+    }
+    for d in Direction8::ALL {
+        assert_eq!(d.opposite().delta(), -d.delta());
+        assert_eq!(
+            d.turn_right().turn_right().delta(),
+            d.delta().rotate_right()
+        );
+        assert_eq!(d.turn_left().turn_right(), d);
+    }
+
+    let p = Point::new(3, -2);
+    assert_eq!(p + Direction::Up.delta() * 4, Point::new(3, -6));
+    assert_eq!(p - Point::new(1, 1), Point::new(2, -3));
+    assert_eq!(p.manhattan(Point::new(-1, 1)), 7);
+}